@@ -0,0 +1,111 @@
+use std::{alloc::Layout, mem::ManuallyDrop};
+
+use crate::{Flake, heap::*, rime::*, track::*};
+
+/// A [`Rime`] over a block allocated with an explicit layout, created by
+/// [`Rime::from_raw_with_layout`].
+///
+/// A plain `Rime` recomputes its block layout on `Drop`. This handle stores the layout it was
+/// given instead and frees the block with it verbatim, so over-aligned or padded blocks from
+/// custom allocation code go back to the allocator exactly as they were requested. Clones share
+/// the block and the layout.
+///
+/// It dereferences to the payload rather than to a `Rime`: a plain `Rime` clone would free the
+/// block with the recomputed layout.
+pub struct AdoptedRime<C: Counter, T: ?Sized> {
+    rime: ManuallyDrop<Rime<C, T>>,
+    layout: Layout,
+}
+
+impl<C: Counter, T: ?Sized> AdoptedRime<C, T> {
+    #[inline(always)]
+    pub(crate) fn new(rime: Rime<C, T>, layout: Layout) -> Self {
+        Self { rime: ManuallyDrop::new(rime), layout }
+    }
+
+    /// Returns the layout the block was allocated with, which is the one it is freed with.
+    #[inline(always)]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Returns the number of `AdoptedRime` clones sharing the block.
+    #[inline(always)]
+    pub fn strong_count(&self) -> StrongCount {
+        self.rime.strong_count()
+    }
+}
+
+impl<C: Counter, T: ?Sized> Clone for AdoptedRime<C, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new((*self.rime).clone(), self.layout)
+    }
+}
+
+impl<C: Counter, T: ?Sized> std::ops::Deref for AdoptedRime<C, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.rime
+    }
+}
+
+impl<C: Counter, T: ?Sized> Drop for AdoptedRime<C, T> {
+    fn drop(&mut self) {
+        let counter_ptr = self.rime.counter_ptr();
+
+        unsafe {
+            if (*counter_ptr).decrement() && (*counter_ptr).release() {
+                poison(counter_ptr.cast(), self.layout);
+                deallocate(counter_ptr.cast(), self.layout);
+                track_free();
+            }
+        }
+    }
+}
+
+/// A [`Flake`] over memory allocated with an explicit layout, created by
+/// [`Flake::from_raw_with_layout`].
+///
+/// The stored layout, not the one recomputed from the value, is passed to the allocator when
+/// the handle is dropped.
+pub struct AdoptedFlake<T: ?Sized> {
+    flake: ManuallyDrop<Flake<T>>,
+    layout: Layout,
+}
+
+impl<T: ?Sized> AdoptedFlake<T> {
+    #[inline(always)]
+    pub(crate) fn new(flake: Flake<T>, layout: Layout) -> Self {
+        Self { flake: ManuallyDrop::new(flake), layout }
+    }
+
+    /// Returns the layout the memory was allocated with, which is the one it is freed with.
+    #[inline(always)]
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+}
+
+impl<T: ?Sized> std::ops::Deref for AdoptedFlake<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.flake
+    }
+}
+
+impl<T: ?Sized> Drop for AdoptedFlake<T> {
+    fn drop(&mut self) {
+        let ptr = self.flake.as_ptr() as *mut u8;
+
+        unsafe {
+            poison(ptr, self.layout);
+            deallocate(ptr, self.layout);
+            track_free();
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use std::{alloc::*, cell::{Cell, RefCell}, marker::PhantomData, ptr::*};
+
+use crate::heap::*;
+
+/// The minimum size of each chunk requested from the global allocator.
+const CHUNK_SIZE: usize = 4096;
+
+/// A bump allocator handing out [`ArenaFlake`]s that are freed all at once.
+///
+/// Allocating from the arena only advances a cursor inside a pre-allocated chunk, which makes
+/// it very cheap to store large batches of `?Sized` values such as `str` or `[u8]`. Individual
+/// values are never deallocated; the arena releases every chunk when it is dropped, and the
+/// borrow checker guarantees no [`ArenaFlake`] outlives it.
+///
+/// Like [`Flake::new`](crate::Flake::new), values are copied byte-by-byte and their destructors never run.
+///
+/// # Example
+/// ```
+/// use kroos::FlakeArena;
+///
+/// let arena = FlakeArena::new();
+/// let hello = arena.alloc("hello");
+/// let bytes = arena.alloc(&[1u8, 2, 3][..]);
+///
+/// assert_eq!(&*hello, "hello");
+/// assert_eq!(&*bytes, &[1, 2, 3]);
+/// ```
+pub struct FlakeArena {
+    chunks: RefCell<Vec<(*mut u8, Layout)>>,
+    cursor: Cell<*mut u8>,
+    end: Cell<*mut u8>,
+}
+
+impl FlakeArena {
+    /// Creates an empty arena. The first chunk is allocated lazily.
+    #[inline]
+    pub fn new() -> Self {
+        Self { chunks: RefCell::new(Vec::new()), cursor: Cell::new(null_mut()), end: Cell::new(null_mut()) }
+    }
+
+    /// Creates an arena whose first chunk can hold at least `bytes` bytes.
+    pub fn with_capacity(bytes: usize) -> Self {
+        let arena = Self::new();
+        if bytes != 0 {
+            arena.grow(Layout::from_size_align(bytes, 1).expect("FlakeArena capacity overflow"));
+        }
+        arena
+    }
+
+    /// Returns the number of chunks requested from the global allocator so far.
+    #[inline]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.borrow().len()
+    }
+
+    /// Copies a `?Sized` value into the arena and returns a `Flake`-like view tied to its lifetime.
+    ///
+    /// # Panics
+    /// Panics if the chunk layout overflows; aborts if the allocator fails.
+    pub fn alloc<T: ?Sized>(&self, value: &T) -> ArenaFlake<'_, T> {
+        unsafe {
+            let raw = self.bump(Layout::for_value(value));
+            copy_nonoverlapping(value as *const T as *const u8, raw, size_of_val(value));
+
+            ArenaFlake { _marker: PhantomData, inner_ptr: from_raw_parts(raw, metadata(value)) }
+        }
+    }
+
+    /// Reserves memory for `layout` in the current chunk, starting a new chunk if it does not fit.
+    fn bump(&self, layout: Layout) -> *mut u8 {
+        if layout.size() == 0 {
+            return without_provenance_mut(layout.align());
+        }
+
+        let cursor = self.cursor.get();
+        if !cursor.is_null() {
+            let padding = cursor.align_offset(layout.align());
+            let remaining = self.end.get() as usize - cursor as usize;
+            if padding != usize::MAX && padding.checked_add(layout.size()).is_some_and(|needed| needed <= remaining) {
+                unsafe {
+                    let start = cursor.add(padding);
+                    self.cursor.set(start.add(layout.size()));
+                    return start;
+                }
+            }
+        }
+
+        let start = self.grow(layout);
+        unsafe { self.cursor.set(start.add(layout.size())) }
+        start
+    }
+
+    /// Allocates a new chunk large enough for `layout` and makes it the current one.
+    fn grow(&self, layout: Layout) -> *mut u8 {
+        let chunk = Layout::from_size_align(layout.size().max(CHUNK_SIZE), layout.align())
+            .expect("FlakeArena chunk overflow");
+
+        unsafe {
+            let raw = allocate(chunk);
+            self.chunks.borrow_mut().push((raw, chunk));
+            self.cursor.set(raw);
+            self.end.set(raw.add(chunk.size()));
+            raw
+        }
+    }
+}
+
+impl Default for FlakeArena {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for FlakeArena {
+    fn drop(&mut self) {
+        for (raw, layout) in self.chunks.get_mut().drain(..) {
+            unsafe { deallocate(raw, layout) }
+        }
+    }
+}
+
+unsafe impl Send for FlakeArena {}
+
+/// A [`Flake`](crate::Flake)-like view of a value stored in a [`FlakeArena`].
+///
+/// Dropping an `ArenaFlake` is a no-op; its memory belongs to the arena, and the `'arena`
+/// lifetime keeps the view from outliving it.
+pub struct ArenaFlake<'arena, T: ?Sized> {
+    _marker: PhantomData<&'arena T>,
+    inner_ptr: *const T,
+}
+
+impl<T: ?Sized> ArenaFlake<'_, T> {
+    /// Returns a raw fat pointer to the value stored in the arena.
+    #[inline(always)]
+    pub fn as_ptr(&self) -> *const T {
+        self.inner_ptr
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for ArenaFlake<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        unsafe { &*self.inner_ptr }
+    }
+}
+
+impl<T: ?Sized> std::ops::Deref for ArenaFlake<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.inner_ptr }
+    }
+}
+
+unsafe impl<T: ?Sized + Sync> Send for ArenaFlake<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for ArenaFlake<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arena_many_strs() {
+        let arena = FlakeArena::new();
+        let words: Vec<String> = (0..2_000).map(|i| format!("word-{i}")).collect();
+        let flakes: Vec<ArenaFlake<'_, str>> = words.iter().map(|w| arena.alloc(w.as_str())).collect();
+
+        for (word, flake) in words.iter().zip(&flakes) {
+            assert_eq!(&**flake, word);
+        }
+
+        assert!(arena.chunk_count() > 1);
+        drop(flakes);
+        drop(arena); // Releases every chunk at once
+    }
+
+    #[test]
+    fn arena_alignment_and_large_values() {
+        let arena = FlakeArena::with_capacity(16);
+        let byte = arena.alloc(&1u8);
+        let wide = arena.alloc(&[7u64; 3][..]);
+        let large = arena.alloc(&[9u8; CHUNK_SIZE * 2][..]);
+        let empty = arena.alloc("");
+
+        assert_eq!(*byte, 1);
+        assert!(wide.as_ptr().cast::<u64>().is_aligned());
+        assert_eq!(&*wide, &[7; 3]);
+        assert!(large.iter().all(|&b| b == 9));
+        assert_eq!(&*empty, "");
+    }
+}
@@ -0,0 +1,828 @@
+use crate::Counter;
+
+/// Whether [`Checked`] verifies its invariants (debug builds or the `checked-counters` feature).
+const CHECKS: bool = cfg!(any(debug_assertions, feature = "checked-counters"));
+
+/// A [`Counter`] adapter that verifies reference-counting invariants of an inner counter `A`.
+///
+/// In debug builds, or when the `checked-counters` feature is enabled, `Checked` panics with
+/// a descriptive message when:
+/// - the count is decremented while already at zero (e.g. a double drop through raw pointers),
+/// - the count is incremented after reaching zero (e.g. a use after free),
+/// - the count would exceed the configured maximum `MAX`.
+///
+/// In release builds without the feature every check compiles out, leaving a zero-overhead
+/// wrapper with the same layout as `A`.
+///
+/// The checks read the count before forwarding the operation, so with atomic counters they
+/// are best-effort diagnostics rather than a synchronization mechanism.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+/// use kroos::{Checked, Rime};
+///
+/// let rime = Rime::<Checked<AtomicUsize, 8>, str>::new("checked");
+/// let cloned = rime.clone();
+/// assert_eq!(cloned.strong_count(), 2);
+/// ```
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct Checked<A: Counter, const MAX: usize = { usize::MAX }>(A);
+
+impl<A: Counter, const MAX: usize> Checked<A, MAX> {
+    /// Returns a reference to the wrapped counter.
+    #[inline(always)]
+    pub fn inner(&self) -> &A {
+        &self.0
+    }
+}
+
+impl<A: Counter, const MAX: usize> Counter for Checked<A, MAX> {
+    #[inline(always)]
+    fn new() -> Self {
+        Self(A::new())
+    }
+
+    #[inline(always)]
+    fn increment(&mut self) {
+        if CHECKS {
+            let count = self.0.count();
+            assert!(count != 0, "Checked counter: increment of a released count (use after free?)");
+            assert!(count < MAX, "Checked counter overflow: count {count} would exceed the configured maximum of {MAX}");
+        }
+        self.0.increment()
+    }
+
+    #[inline(always)]
+    fn increment_by(&mut self, n: usize) {
+        if CHECKS {
+            let count = self.0.count();
+            assert!(n == 0 || count != 0, "Checked counter: increment of a released count (use after free?)");
+            assert!(count.checked_add(n).is_some_and(|total| total <= MAX), "Checked counter overflow: count {count} + {n} would exceed the configured maximum of {MAX}");
+        }
+        self.0.increment_by(n)
+    }
+
+    #[inline(always)]
+    fn decrement(&mut self) -> bool {
+        if CHECKS {
+            assert!(self.0.count() != 0, "Checked counter underflow: decrement of a count already at zero (double drop?)");
+        }
+        self.0.decrement()
+    }
+
+    #[inline(always)]
+    fn decrement_by(&mut self, n: usize) -> bool {
+        if CHECKS {
+            let count = self.0.count();
+            assert!(count >= n, "Checked counter underflow: decrement by {n} of a count of {count} (double drop?)");
+        }
+        self.0.decrement_by(n)
+    }
+
+    #[inline(always)]
+    fn try_increment_nonzero(&mut self) -> bool {
+        if CHECKS {
+            let count = self.0.count();
+            assert!(count < MAX, "Checked counter overflow: count {count} would exceed the configured maximum of {MAX}");
+        }
+        self.0.try_increment_nonzero()
+    }
+
+    #[inline(always)]
+    fn count(&self) -> usize {
+        self.0.count()
+    }
+
+    #[inline(always)]
+    fn count_relaxed(&self) -> usize {
+        self.0.count_relaxed()
+    }
+
+    #[inline(always)]
+    fn is_unique(&self) -> bool {
+        self.0.is_unique()
+    }
+
+    #[inline(always)]
+    fn release(&mut self) -> bool {
+        self.0.release()
+    }
+
+    #[inline(always)]
+    fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> {
+        if CHECKS {
+            assert!(new <= MAX, "Checked counter overflow: count {new} would exceed the configured maximum of {MAX}");
+        }
+        self.0.compare_exchange(current, new)
+    }
+}
+
+/// A [`Counter`] adapter that aligns an inner counter `A` to a 128-byte cache line pair.
+///
+/// Counters of heavily shared atomic `Rime`s are written by every clone and drop. Aligning the
+/// counter keeps it on its own cache line, so those writes do not contend with unrelated data
+/// in neighbouring allocations (false sharing). The block layout accounts for the alignment:
+/// the payload starts at offset 128, and the block itself is 128-byte aligned.
+///
+/// Every block grows by up to 127 bytes of padding, so reserve this for hot, contended counts.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+/// use kroos::{CachePadded, Rime};
+///
+/// let rime = Rime::<CachePadded<AtomicUsize>, [u8]>::new(b"hot");
+/// assert_eq!(rime.counter_ptr() as usize % 128, 0);
+/// assert_eq!(rime.payload_offset(), 128);
+/// ```
+#[repr(align(128))]
+#[derive(Debug)]
+pub struct CachePadded<A: Counter>(A);
+
+impl<A: Counter> CachePadded<A> {
+    /// Returns a reference to the wrapped counter.
+    #[inline(always)]
+    pub fn inner(&self) -> &A {
+        &self.0
+    }
+}
+
+impl<A: Counter> Counter for CachePadded<A> {
+    #[inline(always)] fn new() -> Self { Self(A::new()) }
+    #[inline(always)] fn increment(&mut self) { self.0.increment() }
+    #[inline(always)] fn increment_by(&mut self, n: usize) { self.0.increment_by(n) }
+    #[inline(always)] fn decrement(&mut self) -> bool { self.0.decrement() }
+    #[inline(always)] fn decrement_by(&mut self, n: usize) -> bool { self.0.decrement_by(n) }
+    #[inline(always)] fn try_increment_nonzero(&mut self) -> bool { self.0.try_increment_nonzero() }
+    #[inline(always)] fn count(&self) -> usize { self.0.count() }
+    #[inline(always)] fn count_relaxed(&self) -> usize { self.0.count_relaxed() }
+    #[inline(always)] fn is_unique(&self) -> bool { self.0.is_unique() }
+    #[inline(always)] fn release(&mut self) -> bool { self.0.release() }
+    #[inline(always)] fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> { self.0.compare_exchange(current, new) }
+}
+
+/// A [`Counter`] that never reaches zero, making every `Rime` that uses it immortal.
+///
+/// `Noop` is zero-sized, so it adds nothing to the allocation. Cloning and dropping do no
+/// work, and the block is never freed: it is meant for payloads that live for the whole
+/// program, such as those created with [`Rime::from_static`](crate::Rime::from_static).
+/// Allocating with [`Rime::new`](crate::Rime::new) under `Noop` deliberately leaks.
+///
+/// The reported count is `usize::MAX`, so a `Noop` instance is never considered unique and
+/// in-place mutation is always refused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Noop;
+
+impl Counter for Noop {
+    #[inline(always)] fn new() -> Self { Noop }
+    #[inline(always)] fn increment(&mut self) {}
+    #[inline(always)] fn increment_by(&mut self, _: usize) {}
+    #[inline(always)] fn decrement(&mut self) -> bool { false }
+    #[inline(always)] fn decrement_by(&mut self, _: usize) -> bool { false }
+    #[inline(always)] fn try_increment_nonzero(&mut self) -> bool { true }
+    #[inline(always)] fn count(&self) -> usize { usize::MAX }
+    #[inline(always)] fn release(&mut self) -> bool { false }
+    #[inline(always)] fn compare_exchange(&mut self, _: usize, _: usize) -> Result<usize, usize> { Err(usize::MAX) }
+}
+
+/// A [`Counter`] adapter that records the highest count the allocation ever reached.
+///
+/// Every increment updates an extra atomic maximum, exposed through [`Rime::peak`] (or
+/// [`Profiled::peak`] on the counter itself). This helps understand sharing patterns in
+/// production, at the cost of one more atomic operation per clone and a `usize` per block.
+///
+/// Only available with the `profiled-counters` feature.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+/// use kroos::{Profiled, Rime};
+///
+/// let rime = Rime::<Profiled<AtomicUsize>, str>::new("profiled");
+/// drop((rime.clone(), rime.clone()));
+/// assert_eq!(rime.peak(), 3);
+/// ```
+#[cfg(feature = "profiled-counters")]
+#[derive(Debug)]
+pub struct Profiled<A: Counter> {
+    inner: A,
+    peak: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(feature = "profiled-counters")]
+impl<A: Counter> Profiled<A> {
+    /// Returns the highest count observed so far.
+    #[inline(always)]
+    pub fn peak(&self) -> usize {
+        self.peak.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    fn record(&self) {
+        self.peak.fetch_max(self.inner.count_relaxed(), std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "profiled-counters")]
+impl<A: Counter> Counter for Profiled<A> {
+    #[inline(always)]
+    fn new() -> Self {
+        Self { inner: A::new(), peak: std::sync::atomic::AtomicUsize::new(1) }
+    }
+
+    #[inline(always)]
+    fn increment(&mut self) {
+        self.inner.increment();
+        self.record()
+    }
+
+    #[inline(always)]
+    fn increment_by(&mut self, n: usize) {
+        self.inner.increment_by(n);
+        self.record()
+    }
+
+    #[inline(always)]
+    fn decrement(&mut self) -> bool {
+        self.inner.decrement()
+    }
+
+    #[inline(always)]
+    fn decrement_by(&mut self, n: usize) -> bool {
+        self.inner.decrement_by(n)
+    }
+
+    #[inline(always)]
+    fn try_increment_nonzero(&mut self) -> bool {
+        let incremented = self.inner.try_increment_nonzero();
+        if incremented { self.record() }
+        incremented
+    }
+
+    #[inline(always)]
+    fn count(&self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline(always)]
+    fn count_relaxed(&self) -> usize {
+        self.inner.count_relaxed()
+    }
+
+    #[inline(always)]
+    fn is_unique(&self) -> bool {
+        self.inner.is_unique()
+    }
+
+    #[inline(always)]
+    fn release(&mut self) -> bool {
+        self.inner.release()
+    }
+
+    #[inline(always)]
+    fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> {
+        let result = self.inner.compare_exchange(current, new);
+        if result.is_ok() { self.record() }
+        result
+    }
+}
+
+#[cfg(feature = "profiled-counters")]
+impl<A: Counter, T: ?Sized> crate::Rime<Profiled<A>, T> {
+    /// Returns the highest number of clones that ever shared this allocation.
+    #[inline(always)]
+    pub fn peak(&self) -> usize {
+        unsafe { (*self.counter_ptr()).peak() }
+    }
+}
+
+/// An atomic [`Counter`] that wakes waiters when the count drops back to one.
+///
+/// Besides the count, every block carries a `Mutex` and a `Condvar`, which
+/// [`Rime::wait_until_unique`](crate::Rime::wait_until_unique) parks on until every other clone
+/// has been dropped. Decrements that leave one owner or none take the lock briefly, so only use
+/// this counter where a thread actually hands exclusive access back to another.
+///
+/// Only available with the `std` feature (enabled by default).
+///
+/// # Example
+/// ```
+/// use kroos::{NotifyingCounter, Rime};
+///
+/// let mut rime = Rime::<NotifyingCounter, [u8]>::new(b"job");
+/// let worker = rime.clone();
+/// std::thread::spawn(move || drop(worker));
+///
+/// rime.wait_until_unique()[0] = b'J';
+/// assert_eq!(&*rime, b"Job");
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct NotifyingCounter {
+    count: std::sync::atomic::AtomicUsize,
+    lock: std::sync::Mutex<()>,
+    unique: std::sync::Condvar,
+}
+
+#[cfg(feature = "std")]
+impl NotifyingCounter {
+    /// Blocks the current thread until the count is exactly one.
+    pub fn wait_until_unique(&self) {
+        let mut guard = self.lock.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        while !self.count.is_unique() {
+            guard = self.unique.wait(guard).unwrap_or_else(std::sync::PoisonError::into_inner);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Counter for NotifyingCounter {
+    #[inline(always)]
+    fn new() -> Self {
+        Self { count: Counter::new(), lock: std::sync::Mutex::new(()), unique: std::sync::Condvar::new() }
+    }
+
+    #[inline(always)]
+    fn increment(&mut self) {
+        self.count.increment()
+    }
+
+    #[inline(always)]
+    fn increment_by(&mut self, n: usize) {
+        self.count.increment_by(n)
+    }
+
+    #[inline]
+    fn decrement(&mut self) -> bool {
+        use std::sync::atomic::Ordering;
+
+        let mut current = self.count.load(Ordering::Relaxed);
+        while current > 2 {
+            match self.count.compare_exchange_weak(current, current - 1, Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => return false,
+                Err(actual) => current = actual,
+            }
+        }
+
+        // The steps to one and to zero both happen under the lock: a waiter cannot see the
+        // payload become unique, and the last owner cannot free the block, until the thread
+        // that stepped to one is done with the condvar
+        let _guard = self.lock.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if self.count.decrement() { return true }
+        if self.count.is_unique() { self.unique.notify_all() }
+        false
+    }
+
+    #[inline(always)]
+    fn try_increment_nonzero(&mut self) -> bool {
+        self.count.try_increment_nonzero()
+    }
+
+    #[inline(always)]
+    fn count(&self) -> usize {
+        self.count.count()
+    }
+
+    #[inline(always)]
+    fn count_relaxed(&self) -> usize {
+        self.count.count_relaxed()
+    }
+
+    fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> {
+        // Under the lock for the same reason as `decrement`: a waiter may free the block once
+        // it observes a count of one
+        let _guard = self.lock.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let result = Counter::compare_exchange(&mut self.count, current, new);
+        if result.is_ok() && new == 1 { self.unique.notify_all() }
+        result
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ?Sized> crate::Rime<NotifyingCounter, T> {
+    /// Blocks until every other clone has been dropped, then returns exclusive access to the
+    /// payload.
+    ///
+    /// Waiting on a clone held by the current thread deadlocks.
+    pub fn wait_until_unique(&mut self) -> &mut T {
+        unsafe {
+            (*self.counter_ptr()).wait_until_unique();
+            &mut *self.as_mut_ptr()
+        }
+    }
+}
+
+/// One cache line of a [`Sharded`] counter: a 32-bit count in the low half and a version,
+/// bumped by every change, in the high half.
+#[cfg(feature = "std")]
+#[repr(align(128))]
+#[derive(Debug)]
+struct Shard(std::sync::atomic::AtomicU64);
+
+#[cfg(feature = "std")]
+const SHARD_ONE: u64 = 1 | 1 << 32;
+
+/// An atomic [`Counter`] that spreads the count over `N` cache-line-sized shards.
+///
+/// Each thread is assigned one shard, so threads that clone and drop the same `Rime` mostly
+/// touch different cache lines instead of contending on a single `AtomicUsize`. The total is
+/// the sum of the shards, which makes zero detection the delicate part:
+/// - Increments, and decrements that leave the thread's shard at one or more, are a single
+///   atomic operation on that shard.
+/// - A decrement that would empty a shard takes a small spinlock, empties the shard and then
+///   scans the others. While the lock is held no shard can drop to zero, so a scan that finds
+///   every shard empty proves no other reference exists and this was the last one.
+/// - `count` and `is_unique` sum a snapshot taken by reading every shard twice until no
+///   shard changed in between, so they never miss a reference moving between shards.
+///
+/// The fast path therefore needs a thread to keep its shard above one: it pays off when each
+/// thread holds its own clone and clones and drops around it. A thread that repeatedly clones
+/// a value it does not otherwise hold will take the lock on every drop. Every block grows by
+/// `128 * N` bytes, and each shard counts at most `u32::MAX` references.
+///
+/// Only available with the `std` feature (enabled by default).
+///
+/// # Example
+/// ```
+/// use kroos::{Rime, Sharded};
+///
+/// let hot = Rime::<Sharded<8>, str>::new("config");
+/// std::thread::scope(|scope| {
+///     for _ in 0..4 {
+///         let local = hot.clone();
+///         scope.spawn(move || (0..100).for_each(|_| drop(local.clone())));
+///     }
+/// });
+/// assert!(hot.is_unique());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Sharded<const N: usize> {
+    shards: [Shard; N],
+    lock: std::sync::atomic::AtomicBool,
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> Sharded<N> {
+    /// Returns the shard assigned to the current thread.
+    #[inline]
+    fn local(&self) -> &std::sync::atomic::AtomicU64 {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        thread_local! {
+            static INDEX: usize = NEXT.fetch_add(1, Ordering::Relaxed);
+        }
+        // Thread-local storage may already be gone while a thread exits
+        let index = INDEX.try_with(|index| *index).unwrap_or(0);
+        &self.shards[index % N].0
+    }
+
+    /// Sums the shards from a snapshot in which no shard changed while it was read.
+    fn total(&self) -> usize {
+        use std::sync::atomic::Ordering;
+
+        let read = || self.shards.each_ref().map(|shard| shard.0.load(Ordering::Acquire));
+        let mut before = read();
+        loop {
+            let after = read();
+            if after == before {
+                return after.iter().map(|&shard| (shard as u32) as usize).sum();
+            }
+            before = after;
+        }
+    }
+
+    fn lock(&self) {
+        use std::sync::atomic::Ordering;
+
+        while self.lock.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed).is_err() {
+            std::thread::yield_now();
+        }
+    }
+
+    #[inline]
+    fn unlock(&self) {
+        self.lock.store(false, std::sync::atomic::Ordering::Release)
+    }
+
+    /// Decrements the first shard holding at least `min` references, returning `false` if none
+    /// does.
+    fn decrement_any(&self, min: u32) -> bool {
+        use std::sync::atomic::Ordering;
+
+        for shard in &self.shards {
+            let mut current = shard.0.load(Ordering::Relaxed);
+            while current as u32 >= min {
+                match shard.0.compare_exchange_weak(current, current.wrapping_sub(1).wrapping_add(1 << 32), Ordering::Release, Ordering::Relaxed) {
+                    Ok(_) => return true,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+        false
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> Counter for Sharded<N> {
+    #[inline]
+    fn new() -> Self {
+        const { assert!(N > 0, "Sharded counters need at least one shard") };
+
+        let shards = std::array::from_fn(|_| Shard(std::sync::atomic::AtomicU64::new(0)));
+        let counter = Self { shards, lock: std::sync::atomic::AtomicBool::new(false) };
+        counter.local().store(SHARD_ONE, std::sync::atomic::Ordering::Relaxed);
+        counter
+    }
+
+    #[inline]
+    fn increment(&mut self) {
+        use std::sync::atomic::Ordering;
+
+        // A full shard is refused before the store: adding to it would carry into the version
+        let local = self.local();
+        let mut current = local.load(Ordering::Relaxed);
+        loop {
+            assert!(current as u32 != u32::MAX, "RefCount overflow");
+            match local.compare_exchange_weak(current, current.wrapping_add(SHARD_ONE), Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => return,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    fn decrement(&mut self) -> bool {
+        use std::sync::atomic::Ordering;
+
+        let local = self.local();
+        let mut current = local.load(Ordering::Relaxed);
+        while current as u32 >= 2 {
+            match local.compare_exchange_weak(current, current.wrapping_sub(1).wrapping_add(1 << 32), Ordering::Release, Ordering::Relaxed) {
+                Ok(_) => return false,
+                Err(actual) => current = actual,
+            }
+        }
+
+        self.lock();
+        // Leaving a shard non-empty cannot release the last reference
+        if self.decrement_any(2) {
+            self.unlock();
+            return false;
+        }
+        // Only this thread may now empty a shard, and the reference being dropped is counted in
+        // one that holds exactly one
+        while !self.decrement_any(1) {
+            std::hint::spin_loop();
+        }
+        let last = self.shards.iter().all(|shard| shard.0.load(Ordering::Acquire) as u32 == 0);
+        self.unlock();
+        last
+    }
+
+    fn try_increment_nonzero(&mut self) -> bool {
+        use std::sync::atomic::Ordering;
+
+        // A reference exists only while some shard is non-empty, and none can empty under the lock
+        self.lock();
+        let alive = self.shards.iter().any(|shard| shard.0.load(Ordering::Acquire) as u32 != 0);
+        if alive { self.increment() }
+        self.unlock();
+        alive
+    }
+
+    #[inline]
+    fn count(&self) -> usize {
+        self.total()
+    }
+
+    /// Shards cannot be compared and swapped as a whole, so this always fails, reporting the
+    /// current total.
+    #[inline]
+    fn compare_exchange(&mut self, _: usize, _: usize) -> Result<usize, usize> {
+        Err(self.total())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, sync::atomic::AtomicUsize};
+    use crate::Rime;
+    use super::*;
+
+    static GREETING: Rime<Noop, str> = Rime::from_static("hello, static");
+
+    #[test]
+    fn noop_static() {
+        assert_eq!(&*GREETING, "hello, static");
+
+        let cloned = GREETING.clone();
+        assert_eq!(cloned.as_ptr(), GREETING.as_ptr());
+        assert_eq!(GREETING.strong_count(), usize::MAX);
+        assert!(!cloned.is_unique());
+        drop(cloned);
+        assert_eq!(&*GREETING, "hello, static");
+    }
+
+    #[test]
+    fn checked_forwards() {
+        let rime = Rime::<Checked<AtomicUsize, 3>, str>::new("checked");
+        let a = rime.clone();
+        let b = rime.clone();
+
+        assert_eq!(rime.strong_count(), 3);
+        drop((a, b));
+        assert_eq!(rime.strong_count(), 1);
+    }
+
+    #[test]
+    fn cache_padded_layout() {
+        #[repr(align(128))]
+        struct Aligned(AtomicUsize);
+
+        impl Counter for Aligned {
+            fn new() -> Self { Self(Counter::new()) }
+            fn increment(&mut self) { self.0.increment() }
+            fn decrement(&mut self) -> bool { self.0.decrement() }
+            fn try_increment_nonzero(&mut self) -> bool { self.0.try_increment_nonzero() }
+            fn count(&self) -> usize { self.0.count() }
+        }
+
+        fn check<C: Counter>() {
+            let text = Rime::<C, str>::new("false sharing");
+            let words = Rime::<C, [u64]>::new(&[1, 2, 3]);
+            let single = Rime::<C, u8>::steal(7);
+
+            for (counter, offset, layout) in [
+                (text.counter_ptr() as usize, text.payload_offset(), text.layout()),
+                (words.counter_ptr() as usize, words.payload_offset(), words.layout()),
+                (single.counter_ptr() as usize, single.payload_offset(), single.layout()),
+            ] {
+                assert_eq!(counter % 128, 0);
+                assert_eq!(offset, 128);
+                assert_eq!(layout.align(), 128);
+            }
+            assert_eq!(text.as_ptr() as *const u8 as usize, text.counter_ptr() as usize + 128);
+
+            let clone = words.clone();
+            assert_eq!(words.strong_count(), 2);
+            drop(clone);
+            assert_eq!((&*text, &*words, *single), ("false sharing", &[1, 2, 3][..], 7));
+        }
+
+        check::<Aligned>();
+        check::<CachePadded<AtomicUsize>>();
+        check::<CachePadded<Cell<u8>>>();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sharded_detects_zero_once() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        struct Shared(*mut Sharded<4>);
+        unsafe impl Send for Shared {}
+        unsafe impl Sync for Shared {}
+
+        for _ in 0..50 {
+            let counter = Shared(Box::into_raw(Box::new(Sharded::<4>::new())));
+            let zeroed = AtomicBool::new(false);
+            unsafe { (*counter.0).increment_by(7) } // One reference per thread, plus the original
+
+            std::thread::scope(|scope| {
+                for _ in 0..8 {
+                    let (counter, zeroed) = (&counter, &zeroed);
+                    scope.spawn(move || unsafe {
+                        let counter = &mut *counter.0;
+                        for _ in 0..200 {
+                            counter.increment();
+                            assert!(counter.count() >= 2);
+                            assert!(!counter.decrement());
+                        }
+                        if counter.decrement() {
+                            assert!(!zeroed.swap(true, Ordering::Relaxed)); // Exactly once
+                        }
+                    });
+                }
+            });
+
+            assert!(zeroed.load(Ordering::Relaxed));
+            let counter = unsafe { Box::from_raw(counter.0) };
+            assert_eq!(counter.count(), 0);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sharded_rime() {
+        let rime = Rime::<Sharded<4>, [u64]>::new(&[1, 2, 3]);
+        assert!(rime.is_unique());
+
+        let clones = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4).map(|_| scope.spawn(|| rime.clone_n(3))).collect();
+            handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect::<Vec<_>>()
+        });
+        assert_eq!(rime.strong_count(), 13);
+        assert!(!rime.is_unique());
+        drop(clones);
+        assert!(rime.is_unique());
+
+        let weak = Rime::<crate::WithWeak<Sharded<2>>, str>::new("weak");
+        let downgraded = weak.downgrade();
+        assert_eq!(downgraded.upgrade().as_deref(), Some("weak"));
+        drop(weak);
+        assert!(downgraded.upgrade().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sharded_refuses_full_shard() {
+        use std::sync::atomic::Ordering;
+
+        let mut counter = Sharded::<1>::new();
+        let full = 7 << 32 | u64::from(u32::MAX);
+        counter.shards[0].0.store(full, Ordering::Relaxed);
+
+        let overflow = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| counter.increment()));
+        assert!(overflow.is_err());
+        assert_eq!(counter.shards[0].0.load(Ordering::Relaxed), full); // Neither count nor version moved
+        assert!(!counter.decrement());
+        assert_eq!(counter.count(), u32::MAX as usize - 1);
+    }
+
+    #[cfg(any(debug_assertions, feature = "checked-counters"))]
+    #[test]
+    #[should_panic(expected = "Checked counter underflow")]
+    fn checked_underflow_panics() {
+        let mut counter = <Checked<Cell<u8>>>::new();
+        assert!(counter.decrement());
+        counter.decrement();
+    }
+
+    #[cfg(any(debug_assertions, feature = "checked-counters"))]
+    #[test]
+    #[should_panic(expected = "exceed the configured maximum of 2")]
+    fn checked_max_panics() {
+        let rime = Rime::<Checked<usize, 2>, [u8]>::new(&[1, 2]);
+        let _a = rime.clone();
+        let _b = rime.clone();
+    }
+
+    #[cfg(feature = "profiled-counters")]
+    #[test]
+    fn profiled_peak() {
+        let rime = Rime::<Profiled<AtomicUsize>, [u8]>::new(b"peak");
+        let clones = rime.clone_n(2);
+        let (a, b) = (rime.clone(), rime.clone());
+
+        assert_eq!(rime.peak(), 5);
+        drop((clones, a));
+        assert_eq!(rime.strong_count(), 2);
+        assert_eq!(rime.peak(), 5);
+        drop(b);
+        assert_eq!(rime.peak(), 5);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn notifying_wait_until_unique() {
+        let mut rime = Rime::<NotifyingCounter, [u32]>::new(&[1, 2, 3]);
+        let (a, b) = (rime.clone(), rime.clone());
+
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            drop(a);
+            std::thread::sleep(std::time::Duration::from_millis(20));
+            drop(b);
+        });
+
+        rime.wait_until_unique().reverse();
+        assert_eq!(rime.strong_count(), 1);
+        assert_eq!(&*rime, &[3, 2, 1]);
+        handle.join().unwrap();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn notifying_last_drop_waits_for_notifier() {
+        use std::sync::atomic::Ordering;
+
+        let rime = Rime::<NotifyingCounter, [u8]>::new(b"race");
+        let last = rime.clone();
+        let counter = rime.counter_ptr();
+        std::mem::forget(rime);
+
+        // Stand in for an owner that has stepped the count from two to one and not yet notified
+        let guard = unsafe { (*counter).lock.lock().unwrap() };
+        unsafe { (*counter).count.fetch_sub(1, Ordering::Release) };
+
+        let handle = std::thread::spawn(move || drop(last));
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!handle.is_finished()); // The last owner cannot free the block under the notifier
+        unsafe { (*counter).unique.notify_all() };
+        drop(guard);
+        handle.join().unwrap();
+    }
+}
@@ -1,4 +1,6 @@
-use std::{alloc::*, hash::Hash, marker::PhantomData, ptr::*};
+use std::{alloc::*, ffi::{CStr, c_char}, hash::Hash, marker::PhantomData, ptr::*};
+
+use crate::{AdoptedFlake, SizeLimitExceeded, heap::*, rime::fingerprint, track::*};
 
 /// A low-level heap-allocated wrapper for dynamically-sized types (`?Sized`) without ownership semantics.
 ///
@@ -48,29 +50,43 @@ impl<T: Sized> Flake<T> {
     /// ```
     pub fn steal(value: T) -> Self {
         unsafe {
-            let layout = Layout::new::<T>();
-            let raw = alloc(layout);
-            if raw.is_null() { 
-                dealloc(raw, layout);
-                handle_alloc_error(layout);
-            }
+            let raw = allocate(Layout::new::<T>());
 
             write(raw as *mut T, value);
 
             Self::from_raw(raw as *const T)
         } 
     }
+
+    /// Replaces the heap value and returns the previous one.
+    ///
+    /// Since a `Flake` is always uniquely owned, this never fails.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let mut flake = Flake::steal(1);
+    /// assert_eq!(flake.replace(2), 1);
+    /// assert_eq!(*flake, 2);
+    /// ```
+    #[inline]
+    pub fn replace(&mut self, value: T) -> T {
+        unsafe { std::mem::replace(&mut *self.as_mut_ptr(), value) }
+    }
 }
 
 impl<T: ?Sized> Flake<T> {
     /// Constructs a `Flake` from a raw fat pointer to a heap-allocated value.
     ///
     /// # Safety
-    /// - The pointer must originate from a valid heap allocation compatible with `Layout::for_value`.
+    /// - The pointer must originate from a valid heap allocation compatible with `Layout::for_value`,
+    ///   or be a dangling, well-aligned pointer if the value is zero-sized.
     /// - Caller is responsible for ensuring exclusive ownership and valid metadata.
     /// - `Flake` will take ownership and deallocate the memory on `Drop`.
     #[inline(always)]
     pub unsafe fn from_raw(ptr: *const T) -> Self {
+        track_alloc();
         Self { _marker: PhantomData, inner_ptr: ptr }
     }
 
@@ -82,9 +98,41 @@ impl<T: ?Sized> Flake<T> {
     /// - Same ownership guarantees as [`from_raw`] apply.
     #[inline(always)]
     pub unsafe fn from_raw_parts(ptr: *const u8, metadata: <T as Pointee>::Metadata) -> Self {
+        track_alloc();
         Self { _marker: PhantomData, inner_ptr: from_raw_parts::<T>(ptr, metadata) }
     }
 
+    /// Adopts a value allocated with `layout`, freeing it with that exact layout.
+    ///
+    /// A `Flake` frees with [`Flake::layout`], recomputed from the value's size and alignment.
+    /// The returned [`AdoptedFlake`] stores `layout` instead and passes it to the allocator
+    /// verbatim, so the allocation may be over-aligned or larger than the value.
+    ///
+    /// # Panics
+    /// Panics if the value is larger or more aligned than `layout`.
+    ///
+    /// # Safety
+    /// Same requirements as [`Flake::from_raw`]; the allocation must have been made with `layout`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::alloc::{Layout, alloc};
+    /// use kroos::Flake;
+    ///
+    /// let layout = Layout::from_size_align(8, 64).unwrap();
+    /// let flake = unsafe {
+    ///     let raw = alloc(layout) as *mut u64;
+    ///     raw.write(42);
+    ///     Flake::from_raw_with_layout(raw, layout)
+    /// };
+    /// assert_eq!((*flake, flake.layout()), (42, layout));
+    /// ```
+    pub unsafe fn from_raw_with_layout(ptr: *const T, layout: Layout) -> AdoptedFlake<T> {
+        let value = Layout::for_value_raw(ptr);
+        assert!(value.size() <= layout.size() && value.align() <= layout.align(), "Flake::from_raw_with_layout: value does not fit the layout");
+        AdoptedFlake::new(Self::from_raw(ptr), layout)
+    }
+
     /// Copies a `?Sized` value from a reference into the heap and returns a `Flake`.
     ///
     /// This function allocates memory equal to the size of the value, and copies the raw bytes into the heap.
@@ -107,12 +155,7 @@ impl<T: ?Sized> Flake<T> {
     /// ```
     pub fn new(value: &T) -> Self {
         unsafe {
-            let layout = Layout::for_value(value);
-            let raw = alloc(layout);
-            if raw.is_null() { 
-                dealloc(raw, layout);
-                handle_alloc_error(layout);
-            }
+            let raw = allocate(Layout::for_value(value));
 
             copy_nonoverlapping(value as *const T as *const u8, raw, size_of_val(value));
 
@@ -120,6 +163,97 @@ impl<T: ?Sized> Flake<T> {
         }
     }
 
+    /// Views memory owned elsewhere (an mmap region, a static buffer, an FFI-provided pointer)
+    /// through the `Flake` API, without ever freeing it.
+    ///
+    /// The result is a [`FlakeRef`], which only lends out `&Flake<T>`; growth and other
+    /// ownership-taking methods are out of reach, and dropping it leaves the memory alone.
+    ///
+    /// # Safety
+    /// `ptr` must point to a valid, initialized `T` that is neither mutated nor freed while the
+    /// `FlakeRef` (or anything borrowed from it) is alive.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// static TABLE: [u16; 3] = [1, 2, 3];
+    /// let view = unsafe { Flake::from_borrowed(&TABLE[..] as *const [u16]) };
+    /// assert_eq!((view.len(), view[2]), (3, 3));
+    /// ```
+    #[inline(always)]
+    pub unsafe fn from_borrowed(ptr: *const T) -> FlakeRef<T> {
+        FlakeRef { flake: std::mem::ManuallyDrop::new(Self { _marker: PhantomData, inner_ptr: ptr }) }
+    }
+
+    /// Like [`Flake::new`], but refuses to allocate if the payload would take more than
+    /// `max_bytes`.
+    ///
+    /// # Errors
+    /// Returns [`SizeLimitExceeded`] without allocating if `size_of_val(value)` is greater than
+    /// `max_bytes`.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// assert!(Flake::with_size_limit("four", 4).is_ok());
+    /// assert!(Flake::with_size_limit("five!", 4).is_err());
+    /// ```
+    pub fn with_size_limit(value: &T, max_bytes: usize) -> Result<Self, SizeLimitExceeded> {
+        let size = size_of_val(value);
+        if size > max_bytes { return Err(SizeLimitExceeded { size, limit: max_bytes }) }
+        Ok(Self::new(value))
+    }
+
+    /// Moves a sized value into a `Flake` of an unsized type it coerces to, such as a closure
+    /// into `dyn FnMut()` or an array into `[T]`.
+    ///
+    /// Like [`Flake::steal`], the payload destructor never runs on drop.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let double = Flake::<dyn Fn(u32) -> u32>::new_unsize(|x| x * 2);
+    /// assert_eq!(double(21), 42);
+    /// ```
+    pub fn new_unsize<U: std::marker::Unsize<T>>(value: U) -> Self {
+        unsafe {
+            let raw = allocate(Layout::new::<U>());
+
+            write(raw as *mut U, value);
+
+            Self::from_raw(raw as *const U as *const T)
+        }
+    }
+
+    /// Reads the payload out as a sized `U` if it is exactly `size_of::<U>()` bytes long, freeing
+    /// the block.
+    ///
+    /// This recovers a typed value from a `Flake` holding its raw bytes, such as a POD struct
+    /// serialized into a `Flake<[u8]>`. The read is unaligned, so the payload alignment does
+    /// not matter.
+    ///
+    /// # Errors
+    /// Returns `self` unchanged if the payload size differs from `size_of::<U>()`.
+    ///
+    /// # Safety
+    /// The payload bytes must be a valid value of `U` (e.g. `U` accepts any bit pattern), and
+    /// ownership of any resources they refer to moves to the returned value.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let bytes = Flake::new(&7u32.to_ne_bytes()[..]);
+    /// assert_eq!(unsafe { bytes.try_into_sized::<u32>() }.ok(), Some(7));
+    /// ```
+    pub unsafe fn try_into_sized<U>(self) -> Result<U, Self> {
+        if size_of_val(&*self) != size_of::<U>() { return Err(self) }
+        Ok(read_unaligned(self.inner_ptr as *const U))
+    }
+
     /// Forcibly drops the heap value stored in the `Flake`.
     ///
     /// # Safety
@@ -142,144 +276,884 @@ impl<T: ?Sized> Flake<T> {
         self.inner_ptr
     }
 
+    /// Returns the [`Layout`] of the heap allocation, exactly as passed to the allocator on `Drop`.
+    #[inline(always)]
+    pub fn layout(&self) -> Layout {
+        Layout::for_value(&**self)
+    }
+
     /// Returns a mutable raw fat pointer to the value stored in the heap.
     ///
     /// Allows in-place mutation of the heap value. Use with caution.
     ///
+    /// Taking `&mut self` ties the pointer to exclusive access: no shared borrow of this `Flake`
+    /// (or of its payload) can be live while it is created.
+    ///
     /// # Safety
     /// - Mutating the memory must not violate the original type's layout or invariants.
     /// - The `Flake` must be alive and not concurrently accessed.
     /// - You must ensure there are no aliasing references.
     #[inline(always)]
-    pub fn as_mut_ptr(&self) -> *mut T {
+    pub fn as_mut_ptr(&mut self) -> *mut T {
         self.inner_ptr.cast_mut()
     }
-}
 
-impl<T: ?Sized> Drop for Flake<T> {
-    fn drop(&mut self) {
-        unsafe {
-            dealloc(self.inner_ptr as *mut u8, Layout::for_value(&*self.inner_ptr));
-        }
+    /// Converts the `Flake` into an FFI-safe [`FlakeRaw`] handle without deallocating.
+    ///
+    /// Ownership of the allocation moves into the handle; use [`Flake::from_handle`] to
+    /// reclaim it, otherwise the memory is leaked.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let handle = Flake::new("opaque").into_handle();
+    /// let flake = unsafe { Flake::<str>::from_handle(handle) };
+    /// assert_eq!(&*flake, "opaque");
+    /// ```
+    #[inline(always)]
+    pub fn into_handle(self) -> FlakeRaw<T> {
+        let (data, metadata) = self.inner_ptr.to_raw_parts();
+        std::mem::forget(self);
+        FlakeRaw { data, metadata }
     }
-}
 
-impl<T: ?Sized> AsRef<T> for Flake<T> {
-    #[inline]
-    fn as_ref(&self) -> &T {
-        unsafe { &*self.inner_ptr }
+    /// Reconstructs a `Flake` from a handle produced by [`Flake::into_handle`].
+    ///
+    /// # Safety
+    /// - The handle must originate from [`Flake::into_handle`] with the same `T`.
+    /// - The handle must not have been reclaimed before, and its fields must be unmodified.
+    #[inline(always)]
+    pub unsafe fn from_handle(handle: FlakeRaw<T>) -> Self {
+        Self { _marker: PhantomData, inner_ptr: from_raw_parts(handle.data, handle.metadata) }
     }
 }
 
-impl<T: ?Sized> std::ops::Deref for Flake<T> {
-    type Target = T;
+/// The error returned by the fallible growth methods of [`Flake`], such as [`Flake::try_push`].
+///
+/// Mirrors `std::collections::TryReserveError`, whose kind cannot be constructed outside `std`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The new length overflows `usize` or the maximum layout size.
+    CapacityOverflow,
+    /// The allocator failed to provide a block of the given layout.
+    AllocError { layout: Layout },
+}
 
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*self.inner_ptr }
+impl std::fmt::Display for TryReserveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CapacityOverflow => f.write_str("memory allocation failed because the computed capacity exceeded the maximum"),
+            Self::AllocError { layout } => write!(f, "memory allocation of {} bytes failed", layout.size()),
+        }
     }
 }
 
+impl std::error::Error for TryReserveError {}
 
-impl<T: ?Sized> Eq for Flake<T> { }
-impl<T: ?Sized> PartialEq for Flake<T> {
+/// An FFI-safe, opaque representation of a [`Flake`].
+///
+/// The handle bundles the data pointer and the pointer metadata (length, vtable, etc.)
+/// in a `#[repr(C)]` layout, so foreign code can hold a kroos allocation and hand it back later.
+///
+/// # Safety
+/// - The C side must treat the handle as opaque and must **not** mutate its fields.
+/// - A handle owns its allocation; it must be turned back into a `Flake` exactly once
+///   via [`Flake::from_handle`], or the memory leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct FlakeRaw<T: ?Sized> {
+    pub data: *const (),
+    pub metadata: <T as Pointee>::Metadata,
+}
+
+impl Flake<str> {
+    /// Returns the length of the string in bytes, read from the pointer metadata without touching
+    /// the payload.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let value = Flake::new("héllo");
+    /// assert_eq!(value.len(), 6);
+    /// assert!(!value.is_empty());
+    /// ```
     #[inline(always)]
-    fn eq(&self, other: &Self) -> bool {
-        addr_eq(self.inner_ptr, other.inner_ptr)
+    pub fn len(&self) -> usize {
+        metadata(self.inner_ptr)
     }
-}
 
-impl<T: ?Sized + Ord> Ord for Flake<T> {
+    /// Returns `true` if the string has a length of zero.
     #[inline(always)]
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        unsafe { (&*self.inner_ptr).cmp(&*other) }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a 64-bit FNV-1a hash of the UTF-8 bytes, stable across allocations and runs.
+    #[inline]
+    pub fn content_fingerprint(&self) -> u64 {
+        fingerprint(self.as_bytes())
     }
 }
 
-impl<T: ?Sized + PartialOrd> PartialOrd for Flake<T> {
+impl<T> Flake<[T]> {
+    /// Returns the length of the slice in elements, read from the pointer metadata without touching
+    /// the payload.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let value = Flake::new(&[1u32, 2, 3][..]);
+    /// assert_eq!(value.len(), 3);
+    /// assert!(!value.is_empty());
+    /// ```
     #[inline(always)]
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        unsafe { (&*self.inner_ptr).partial_cmp(&*other) }
+    pub fn len(&self) -> usize {
+        metadata(self.inner_ptr)
     }
-}
 
-impl<T: ?Sized + Hash> Hash for Flake<T> {
+    /// Returns `true` if the slice has a length of zero.
     #[inline(always)]
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        unsafe { (&*self.inner_ptr).hash(state) }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-}
 
-unsafe impl<T: ?Sized> Send for Flake<T> {}
-unsafe impl<T: ?Sized> Sync for Flake<T> {}
+    /// Appends `value`, reallocating the buffer to fit one more element.
+    ///
+    /// `Flake` has no spare capacity, so every call reallocates (the allocator may grow the
+    /// block in place). Prefer [`Flake::extend_from_slice`] to append several elements at once.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let mut flake = Flake::new(&[1u8, 2][..]);
+    /// flake.push(3);
+    /// assert_eq!(&*flake, &[1, 2, 3]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        unsafe {
+            let len = self.len();
+            let raw = self.grow(1);
+            write(raw.add(len), value);
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::Flake;
+    /// Appends a copy of every element of `values`, reallocating the buffer once.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let mut flake = Flake::new("ab".as_bytes());
+    /// flake.extend_from_slice(b"cd");
+    /// assert_eq!(&*flake, b"abcd");
+    /// ```
+    pub fn extend_from_slice(&mut self, values: &[T]) where T: Copy {
+        if values.is_empty() { return }
+        unsafe {
+            let len = self.len();
+            let raw = self.grow(values.len());
+            copy_nonoverlapping(values.as_ptr(), raw.add(len), values.len());
+        }
+    }
 
-    #[test]
-    fn flake_as_bytes() {
-        let input: &str = "hello";
-        let flake = Flake::new(input);
-        assert_eq!(&*flake, "hello");
-        println!("{:?} {:?}", flake.len(), b"hello");
+    /// Appends `value` like [`Flake::push`], but reports allocation failure instead of aborting.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the new length overflows or the allocator fails; the
+    /// `Flake` is left unchanged and `value` is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let mut flake = Flake::new(&[1u8, 2][..]);
+    /// flake.try_push(3).unwrap();
+    /// assert_eq!(&*flake, &[1, 2, 3]);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), TryReserveError> {
+        unsafe {
+            let len = self.len();
+            let raw = self.try_grow(1)?;
+            write(raw.add(len), value);
+        }
+        Ok(())
     }
 
-    #[test]
-    fn flake_from_str() {
-        let input: &str = "hello";
-        let flake = Flake::new(input);
-        assert_eq!(&*flake, "hello");
-        assert_eq!(flake.as_ref(), "hello");
+    /// Appends a copy of every element of `values` like [`Flake::extend_from_slice`], but
+    /// reports allocation failure instead of aborting.
+    ///
+    /// # Errors
+    /// Returns a [`TryReserveError`] if the new length overflows or the allocator fails; the
+    /// `Flake` is left unchanged.
+    pub fn try_extend_from_slice(&mut self, values: &[T]) -> Result<(), TryReserveError> where T: Copy {
+        if values.is_empty() { return Ok(()) }
+        unsafe {
+            let len = self.len();
+            let raw = self.try_grow(values.len())?;
+            copy_nonoverlapping(values.as_ptr(), raw.add(len), values.len());
+        }
+        Ok(())
     }
 
-    #[test]
-    fn flake_from_slice() {
-        let slice: &[u8] = &[1, 2, 3, 4];
-        let flake = Flake::new(slice);
-        assert_eq!(&*flake, &[1, 2, 3, 4]);
+    /// Reallocates the buffer for `additional` more elements and returns the new data pointer.
+    ///
+    /// The slice length is updated immediately, so the caller must initialize the new tail.
+    unsafe fn grow(&mut self, additional: usize) -> *mut T {
+        match self.try_grow(additional) {
+            Ok(raw) => raw,
+            Err(TryReserveError::CapacityOverflow) => panic!("Flake layout overflow"),
+            Err(TryReserveError::AllocError { layout }) => handle_alloc_error(layout),
+        }
     }
 
-    #[test]
-    fn flake_eq_cmp_ord() {
-        let a = Flake::new("abc");
-        let b = Flake::new("abc");
-        let c = Flake::new("xyz");
+    /// Fallible core of [`Flake::grow`]; on error the buffer and its length are unchanged.
+    unsafe fn try_grow(&mut self, additional: usize) -> Result<*mut T, TryReserveError> {
+        let len = self.len().checked_add(additional).ok_or(TryReserveError::CapacityOverflow)?;
+        let layout = Layout::array::<T>(len).map_err(|_| TryReserveError::CapacityOverflow)?;
 
-        assert!(a != b); // different pointers
-        assert_eq!(*a, *b); // same contents
-        assert!(a < c);
+        let raw = try_reallocate(self.inner_ptr as *mut u8, self.layout(), layout.size()) as *mut T;
+        if raw.is_null() { return Err(TryReserveError::AllocError { layout }) }
+
+        self.inner_ptr = slice_from_raw_parts(raw, len);
+        Ok(raw)
     }
 
-    #[test]
-    fn flake_mutate_bytes() {
-        let slice: &[u8] = &[1, 2, 3];
-        let flake = Flake::new(slice);
+    /// Returns the payload as a mutable slice.
+    ///
+    /// This is sound because a `Flake` is the sole owner of its allocation, so the borrow
+    /// of `self` rules out any other access for the slice's lifetime.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let mut flake = Flake::new(&[3, 1, 2][..]);
+    /// flake.as_mut_slice().sort();
+    /// assert_eq!(&*flake, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { &mut *self.as_mut_ptr() }
+    }
 
-        unsafe {
-            let ptr = flake.as_mut_ptr();
-            (*ptr)[1] = 9;
-        }
+    /// Fills every element of the slice with `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let mut flake = Flake::new(&[0u8; 4][..]);
+    /// flake.fill(0xFF);
+    /// assert_eq!(&*flake, &[0xFF; 4]);
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, value: T) where T: Copy {
+        unsafe { (*self.as_mut_ptr()).fill(value) }
+    }
 
-        assert_eq!(&*flake, &[1, 9, 3]);
+    /// Reverses the order of the elements in place.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let mut flake = Flake::new(&[1, 2, 3][..]);
+    /// flake.reverse();
+    /// assert_eq!(&*flake, &[3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        unsafe { (*self.as_mut_ptr()).reverse() }
     }
 
-    #[test]
-    fn flake_steal_string() {
-        let string = String::from("yo");
-        let flake = Flake::steal(string);
-        assert_eq!(&*flake, "yo");
+    /// Returns the first element and the rest of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        (**self).split_first()
     }
 
-    #[test]
-    fn flake_from_raw_manual() {
-        use std::{alloc::*, ptr::copy_nonoverlapping, ptr::from_raw_parts_mut};
+    /// Returns the last element and the rest of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        (**self).split_last()
+    }
 
-        let slice: &[u8] = &[10, 20, 30];
-        let layout = Layout::for_value(slice);
-        unsafe {
-            let raw = alloc(layout);
-            assert!(!raw.is_null());
+    /// Returns an iterator over `size` elements at a time; the last chunk may be shorter.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    #[inline]
+    pub fn chunks(&self, size: usize) -> std::slice::Chunks<'_, T> {
+        (**self).chunks(size)
+    }
+
+    /// Returns an iterator over exactly `size` elements at a time, skipping the remainder.
+    ///
+    /// The leftover elements are available through [`ChunksExact::remainder`](std::slice::ChunksExact::remainder).
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    #[inline]
+    pub fn chunks_exact(&self, size: usize) -> std::slice::ChunksExact<'_, T> {
+        (**self).chunks_exact(size)
+    }
+}
+
+impl Flake<[u8]> {
+    /// Feeds the bytes to `state` with a single [`Hasher::write`](std::hash::Hasher::write) call.
+    ///
+    /// Unlike the [`Hash`] implementation, which forwards to `[u8]` and prefixes the length, this
+    /// writes the raw bytes only. It suits checksums and content-addressed keys where the digest
+    /// must match hashing the same bytes elsewhere. Two payloads whose concatenations coincide
+    /// hash alike, so prefer `Hash` for map keys made of several fields.
+    ///
+    /// # Example
+    /// ```
+    /// use std::hash::{DefaultHasher, Hasher};
+    /// use kroos::Flake;
+    ///
+    /// let (mut a, mut b) = (DefaultHasher::new(), DefaultHasher::new());
+    /// Flake::new(&b"digest"[..]).hash_bytes(&mut a);
+    /// b.write(b"digest");
+    /// assert_eq!(a.finish(), b.finish());
+    /// ```
+    #[inline]
+    pub fn hash_bytes<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write(self)
+    }
+
+    /// Returns a 64-bit FNV-1a hash of the bytes, stable across allocations and runs, and equal
+    /// to [`Rime::content_fingerprint`](crate::Rime::content_fingerprint) for the same bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::{Flake, Rime};
+    ///
+    /// let flake = Flake::new(&b"bytes"[..]);
+    /// assert_eq!(flake.content_fingerprint(), Rime::<u8, [u8]>::new(b"bytes").content_fingerprint());
+    /// ```
+    #[inline]
+    pub fn content_fingerprint(&self) -> u64 {
+        fingerprint(self)
+    }
+
+    /// Reads exactly `len` bytes from `reader` into a new `Flake`, without an intermediate `Vec`.
+    ///
+    /// The buffer is allocated once at its final size and zeroed before
+    /// [`read_exact`](std::io::Read::read_exact) fills it.
+    ///
+    /// # Errors
+    /// Returns the error of `read_exact`, e.g. [`UnexpectedEof`](std::io::ErrorKind::UnexpectedEof)
+    /// if the reader ends early. The partially filled buffer is freed.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use kroos::Flake;
+    ///
+    /// let mut input = Cursor::new(b"headerbody");
+    /// let header = Flake::from_reader(&mut input, 6).unwrap();
+    /// assert_eq!(&*header, b"header");
+    /// ```
+    pub fn from_reader<R: std::io::Read + ?Sized>(reader: &mut R, len: usize) -> std::io::Result<Self> {
+        let mut flake = unsafe {
+            let raw = allocate(Layout::array::<u8>(len).expect("Flake layout overflow"));
+            raw.write_bytes(0, len);
+            Self::from_raw_parts(raw, len)
+        };
+
+        reader.read_exact(flake.as_mut_slice())?;
+        Ok(flake)
+    }
+}
+
+impl Flake<CStr> {
+    /// Copies a nul-terminated C string into the heap, terminator included.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Flake;
+    ///
+    /// let flake = Flake::from_cstr(c"abc");
+    /// assert_eq!(flake.to_bytes(), b"abc");
+    /// ```
+    #[inline(always)]
+    pub fn from_cstr(value: &CStr) -> Self {
+        Self::new(value)
+    }
+
+    /// Returns a pointer to the first character of the string, terminated by a nul byte.
+    ///
+    /// # Safety
+    /// Do not dereference the pointer after the `Flake` is dropped.
+    #[inline(always)]
+    pub fn as_c_ptr(&self) -> *const c_char {
+        self.inner_ptr as *const c_char
+    }
+}
+
+impl<T: ?Sized> Drop for Flake<T> {
+    fn drop(&mut self) {
+        unsafe {
+            // Taken once, up front: after `poison` there is no valid `T` left to reference
+            let layout = self.layout();
+            poison(self.inner_ptr as *mut u8, layout);
+            deallocate(self.inner_ptr as *mut u8, layout);
+            track_free();
+        }
+    }
+}
+
+/// Adopts the box's allocation without copying.
+///
+/// A `Box<T>` comes from the global allocator with `Layout::for_value`, which is exactly how
+/// `Flake` frees it. Like every `Flake`, the payload's destructor will not run; prefer POD types.
+///
+/// # Example
+/// ```
+/// use kroos::Flake;
+///
+/// let boxed: Box<[u8]> = Box::new([1, 2, 3]);
+/// let flake = Flake::from(boxed);
+/// assert_eq!(&*flake, &[1, 2, 3]);
+/// ```
+impl<T: ?Sized> From<Box<T>> for Flake<T> {
+    #[inline]
+    fn from(value: Box<T>) -> Self {
+        unsafe { Self::from_raw(Box::into_raw(value)) }
+    }
+}
+
+impl<T: ?Sized> AsRef<T> for Flake<T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        unsafe { &*self.inner_ptr }
+    }
+}
+
+/// `Flake` uniquely owns its buffer, so it can lend it out mutably, e.g. to [`std::io::Read::read`].
+impl<T> AsMut<[T]> for Flake<[T]> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: ?Sized> std::ops::Deref for Flake<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.inner_ptr }
+    }
+}
+
+
+impl<T: ?Sized> Eq for Flake<T> { }
+impl<T: ?Sized> PartialEq for Flake<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        addr_eq(self.inner_ptr, other.inner_ptr)
+    }
+}
+
+/// Compares the string contents, so `flake == "literal"` works.
+impl PartialEq<str> for Flake<str> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<&str> for Flake<str> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+/// Compares the slice contents element by element.
+impl<T: PartialEq> PartialEq<[T]> for Flake<[T]> {
+    #[inline(always)]
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+
+impl<T: PartialEq> PartialEq<&[T]> for Flake<[T]> {
+    #[inline(always)]
+    fn eq(&self, other: &&[T]) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: ?Sized + Ord> Ord for Flake<T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        unsafe { (&*self.inner_ptr).cmp(&**other) }
+    }
+}
+
+impl<T: ?Sized + PartialOrd> PartialOrd for Flake<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        unsafe { (&*self.inner_ptr).partial_cmp(&**other) }
+    }
+}
+
+impl<T: ?Sized + Hash> Hash for Flake<T> {
+    #[inline(always)]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        unsafe { (&*self.inner_ptr).hash(state) }
+    }
+}
+
+unsafe impl<T: ?Sized> Send for Flake<T> {}
+unsafe impl<T: ?Sized> Sync for Flake<T> {}
+
+/// A clone-on-write pointer to a `?Sized` value that is either borrowed or held in a [`Flake`].
+///
+/// Like [`std::borrow::Cow`], but the owned side is a single-allocation `Flake<T>` rather than
+/// `T::Owned`, so it works for any DST `Flake` supports without reference counting. Both
+/// variants dereference to `T`.
+///
+/// # Example
+/// ```
+/// use kroos::FlakeCow;
+///
+/// let mut cow = FlakeCow::Borrowed(&[1u8, 2, 3][..]);
+/// assert!(!cow.is_owned());
+///
+/// cow.to_mut().reverse(); // Copies into a `Flake` first
+/// assert_eq!(&*cow, &[3, 2, 1]);
+/// assert!(cow.is_owned());
+/// ```
+pub enum FlakeCow<'a, T: ?Sized> {
+    Borrowed(&'a T),
+    Owned(Flake<T>),
+}
+
+impl<T: ?Sized> FlakeCow<'_, T> {
+    /// Returns `true` if the value is held in an owned [`Flake`].
+    #[inline(always)]
+    pub fn is_owned(&self) -> bool {
+        matches!(self, Self::Owned(_))
+    }
+
+    /// Copies the value into a new, independent [`Flake`], whichever variant this is.
+    #[inline]
+    pub fn to_owned(&self) -> Flake<T> {
+        Flake::new(self)
+    }
+
+    /// Converts into a [`Flake`], copying only if the value is borrowed.
+    #[inline]
+    pub fn into_owned(self) -> Flake<T> {
+        match self {
+            Self::Borrowed(value) => Flake::new(value),
+            Self::Owned(flake) => flake,
+        }
+    }
+
+    /// Returns the owned [`Flake`], copying the borrowed value into one first if needed.
+    pub fn to_mut(&mut self) -> &mut Flake<T> {
+        if let Self::Borrowed(value) = *self {
+            *self = Self::Owned(Flake::new(value));
+        }
+        match self {
+            Self::Owned(flake) => flake,
+            Self::Borrowed(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T: ?Sized> std::ops::Deref for FlakeCow<'_, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        match self {
+            Self::Borrowed(value) => value,
+            Self::Owned(flake) => flake,
+        }
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a T> for FlakeCow<'a, T> {
+    #[inline(always)]
+    fn from(value: &'a T) -> Self {
+        Self::Borrowed(value)
+    }
+}
+
+impl<T: ?Sized> From<Flake<T>> for FlakeCow<'_, T> {
+    #[inline(always)]
+    fn from(flake: Flake<T>) -> Self {
+        Self::Owned(flake)
+    }
+}
+
+/// A [`Flake`] that owns its payload and runs its destructor on `Drop`.
+///
+/// A plain `Flake` only frees memory, so storing a `String` with [`Flake::steal`] leaks its
+/// buffer unless [`Flake::drop_inner`] is called by hand. `FlakeOwned` drops the payload exactly
+/// once, either on `Drop` or by handing it back through [`FlakeOwned::into_inner`]. It keeps
+/// the single-pointer layout of `Flake`; use the plain `Flake` when the payload is POD and the
+/// destructor call is pure overhead.
+///
+/// Slice payloads (`FlakeOwned<[T]>`) drop their elements in order, front to back, as
+/// `drop_in_place` does for slices; code releasing resources in sequence can rely on it.
+///
+/// # Example
+/// ```
+/// use kroos::FlakeOwned;
+///
+/// let mut owned = FlakeOwned::steal(vec![String::from("dropped")]);
+/// owned.push(String::from("as well"));
+/// assert_eq!(owned.len(), 2);
+/// ```
+pub struct FlakeOwned<T: ?Sized> {
+    _marker: PhantomData<*const T>,
+    flake: Flake<T>,
+}
+
+impl<T> FlakeOwned<T> {
+    /// Moves a value into a new allocation that will drop it.
+    #[inline]
+    pub fn steal(value: T) -> Self {
+        Self { _marker: PhantomData, flake: Flake::steal(value) }
+    }
+
+    /// Moves the payload out and frees the allocation without running the destructor.
+    pub fn into_inner(self) -> T {
+        let this = std::mem::ManuallyDrop::new(self);
+        unsafe {
+            let flake = read(&this.flake);
+            read(flake.as_ptr())
+        }
+    }
+}
+
+impl<T: ?Sized> FlakeOwned<T> {
+    /// Takes over a `Flake` whose payload is a valid, owned value.
+    ///
+    /// # Safety
+    /// The payload must not have been dropped (e.g. through [`Flake::drop_inner`]) and must not
+    /// be a bitwise copy of a value still owned elsewhere, such as one made by [`Flake::new`]
+    /// from a `Drop` type.
+    #[inline(always)]
+    pub unsafe fn from_flake(flake: Flake<T>) -> Self {
+        Self { _marker: PhantomData, flake }
+    }
+}
+
+/// Adopts the box's allocation without copying; the elements are dropped with the `FlakeOwned`.
+impl<T> From<Box<[T]>> for FlakeOwned<[T]> {
+    #[inline]
+    fn from(value: Box<[T]>) -> Self {
+        unsafe { Self::from_flake(Flake::from(value)) }
+    }
+}
+
+impl<T: ?Sized> Drop for FlakeOwned<T> {
+    fn drop(&mut self) {
+        // The payload is dropped first; the `Flake` field then frees the block.
+        unsafe { self.flake.drop_inner() }
+    }
+}
+
+impl<T: ?Sized> std::ops::Deref for FlakeOwned<T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        &self.flake
+    }
+}
+
+impl<T: ?Sized> std::ops::DerefMut for FlakeOwned<T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.flake.as_mut_ptr() }
+    }
+}
+
+unsafe impl<T: ?Sized + Send> Send for FlakeOwned<T> {}
+unsafe impl<T: ?Sized + Sync> Sync for FlakeOwned<T> {}
+
+/// A [`Flake`] over memory it does not own, made by [`Flake::from_borrowed`].
+///
+/// It dereferences to `Flake<T>` for reading only, and its `Drop` never frees the memory. The
+/// allocation counter of the `track-allocations` feature does not see it either.
+pub struct FlakeRef<T: ?Sized> {
+    flake: std::mem::ManuallyDrop<Flake<T>>,
+}
+
+impl<T: ?Sized> Clone for FlakeRef<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        unsafe { Flake::from_borrowed(self.flake.inner_ptr) }
+    }
+}
+
+impl<T: ?Sized> std::ops::Deref for FlakeRef<T> {
+    type Target = Flake<T>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Flake<T> {
+        &self.flake
+    }
+}
+
+/// An [`std::io::Write`] cursor over a uniquely owned `Flake<[u8]>`.
+///
+/// Writes overwrite the buffer from the current position onward. A bounded cursor
+/// ([`FlakeWriteCursor::new`]) stops at the end of the buffer and reports short writes, like
+/// writing into a `&mut [u8]`; a growing cursor ([`FlakeWriteCursor::growing`]) reallocates
+/// the `Flake` to append whatever does not fit. `Flake` keeps no spare capacity, so a growing
+/// cursor reallocates on every write past the end: pre-size the buffer when the output size is
+/// roughly known.
+///
+/// # Example
+/// ```
+/// use std::io::Write;
+/// use kroos::{Flake, FlakeWriteCursor};
+///
+/// let mut cursor = FlakeWriteCursor::growing(Flake::new(&[0u8; 4][..]));
+/// write!(cursor, "id={}", 42).unwrap();
+/// assert_eq!(&*cursor.into_inner(), b"id=42");
+/// ```
+pub struct FlakeWriteCursor {
+    flake: Flake<[u8]>,
+    position: usize,
+    grow: bool,
+}
+
+impl FlakeWriteCursor {
+    /// Creates a cursor at the start of `flake` that never writes past its end.
+    #[inline]
+    pub fn new(flake: Flake<[u8]>) -> Self {
+        Self { flake, position: 0, grow: false }
+    }
+
+    /// Creates a cursor at the start of `flake` that grows the buffer as needed.
+    #[inline]
+    pub fn growing(flake: Flake<[u8]>) -> Self {
+        Self { flake, position: 0, grow: true }
+    }
+
+    /// Returns the number of bytes written so far, which is where the next write starts.
+    #[inline(always)]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns a reference to the underlying buffer.
+    #[inline(always)]
+    pub fn get_ref(&self) -> &Flake<[u8]> {
+        &self.flake
+    }
+
+    /// Returns the underlying buffer, including any bytes past the position.
+    #[inline]
+    pub fn into_inner(self) -> Flake<[u8]> {
+        self.flake
+    }
+}
+
+impl std::io::Write for FlakeWriteCursor {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let available = self.flake.len() - self.position;
+        let fits = buf.len().min(available);
+        self.flake.as_mut_slice()[self.position..self.position + fits].copy_from_slice(&buf[..fits]);
+
+        let written = if self.grow {
+            self.flake.extend_from_slice(&buf[fits..]);
+            buf.len()
+        } else {
+            fits
+        };
+        self.position += written;
+        Ok(written)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Flake, FlakeCow, FlakeOwned, FlakeRef, FlakeWriteCursor, SizeLimitExceeded, TryReserveError};
+
+    #[test]
+    fn flake_as_bytes() {
+        let input: &str = "hello";
+        let flake = Flake::new(input);
+        assert_eq!(&*flake, "hello");
+        println!("{:?} {:?}", flake.len(), b"hello");
+    }
+
+    #[test]
+    fn flake_from_str() {
+        let input: &str = "hello";
+        let flake = Flake::new(input);
+        assert_eq!(&*flake, "hello");
+        assert_eq!(flake.as_ref(), "hello");
+    }
+
+    #[test]
+    fn flake_from_slice() {
+        let slice: &[u8] = &[1, 2, 3, 4];
+        let flake = Flake::new(slice);
+        assert_eq!(&*flake, &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn flake_eq_cmp_ord() {
+        let a = Flake::new("abc");
+        let b = Flake::new("abc");
+        let c = Flake::new("xyz");
+
+        assert!(a != b); // different pointers
+        assert_eq!(*a, *b); // same contents
+        assert!(a < c);
+    }
+
+    #[test]
+    fn flake_mutate_bytes() {
+        let slice: &[u8] = &[1, 2, 3];
+        let mut flake = Flake::new(slice);
+
+        unsafe {
+            let ptr = flake.as_mut_ptr();
+            (*ptr)[1] = 9;
+        }
+
+        assert_eq!(&*flake, &[1, 9, 3]);
+    }
+
+    #[test]
+    fn flake_steal_string() {
+        let string = String::from("yo");
+        let flake = Flake::steal(string);
+        assert_eq!(&*flake, "yo");
+    }
+
+    #[test]
+    fn flake_from_raw_manual() {
+        use std::{alloc::*, ptr::copy_nonoverlapping, ptr::from_raw_parts_mut};
+
+        let slice: &[u8] = &[10, 20, 30];
+        let layout = Layout::for_value(slice);
+        unsafe {
+            let raw = alloc(layout);
+            assert!(!raw.is_null());
             copy_nonoverlapping(slice.as_ptr(), raw, slice.len());
 
             let ptr = from_raw_parts_mut::<[u8]>(raw as *mut (), slice.len());
@@ -287,4 +1161,412 @@ mod tests {
             assert_eq!(&*flake, &[10, 20, 30]);
         }
     }
+
+    #[test]
+    fn flake_from_cstr() {
+        use std::ffi::{CStr, c_char};
+
+        unsafe extern "C" {
+            fn strlen(s: *const c_char) -> usize;
+        }
+
+        let flake = Flake::from_cstr(c"hello");
+        assert_eq!(flake.to_bytes_with_nul(), b"hello\0");
+        assert_eq!(AsRef::<CStr>::as_ref(&flake), c"hello");
+        assert_eq!(unsafe { strlen(flake.as_c_ptr()) }, 5);
+    }
+
+    #[test]
+    fn flake_handle_round_trip() {
+        let flake = Flake::new("handle");
+        let ptr = flake.as_ptr();
+
+        let handle = flake.into_handle();
+        assert_eq!(handle.metadata, 6);
+        assert_eq!(handle.data, ptr as *const ());
+
+        let restored = unsafe { Flake::<str>::from_handle(handle) };
+        assert_eq!(restored.as_ptr(), ptr);
+        assert_eq!(&*restored, "handle");
+    }
+
+    #[test]
+    fn flake_zero_sized() {
+        let empty_str = Flake::new("");
+        let empty_slice = Flake::new(&[] as &[u64]);
+        let unit = Flake::steal(());
+
+        assert_eq!(&*empty_str, "");
+        assert!(empty_slice.is_empty());
+        assert!(empty_slice.as_ptr().cast::<u64>().is_aligned());
+        assert_eq!(*unit, ());
+    }
+
+    #[test]
+    fn flake_fill_and_reverse() {
+        let mut buffer = Flake::new(&[1u8, 2, 3, 4][..]);
+        buffer.reverse();
+        assert_eq!(&*buffer, &[4, 3, 2, 1]);
+
+        buffer.fill(0xFF);
+        assert_eq!(&*buffer, &[0xFF; 4]);
+
+        let mut empty = Flake::new(&[] as &[u8]);
+        empty.fill(0);
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn flake_layout() {
+        #[repr(align(64))]
+        struct Aligned(#[allow(dead_code)] u8);
+
+        let flake = Flake::steal(Aligned(1));
+        assert_eq!((flake.layout().size(), flake.layout().align()), (64, 64));
+        assert_eq!(flake.as_ptr() as usize % 64, 0);
+
+        let slice = Flake::new(&[0u16; 3][..]);
+        assert_eq!((slice.layout().size(), slice.layout().align()), (6, 2));
+    }
+
+    #[test]
+    fn flake_replace_string() {
+        let mut flake = Flake::steal(String::from("old"));
+        let old = flake.replace(String::from("new"));
+
+        assert_eq!(old, "old");
+        assert_eq!(&*flake, "new");
+        unsafe { flake.drop_inner() }
+    }
+
+    #[test]
+    fn flake_split_first_last() {
+        let flake = Flake::new(&[1u32, 2, 3][..]);
+        assert_eq!(flake.split_first(), Some((&1, &[2, 3][..])));
+        assert_eq!(flake.split_last(), Some((&3, &[1, 2][..])));
+        assert_eq!(Flake::new(&[] as &[u32]).split_first(), None);
+    }
+
+    #[test]
+    fn flake_chunks() {
+        let flake = Flake::new(&[1u8, 2, 3, 4, 5, 6, 7][..]);
+        assert_eq!(flake.chunks(3).map(<[u8]>::len).collect::<Vec<_>>(), [3, 3, 1]);
+        assert_eq!(flake.chunks_exact(3).remainder(), &[7]);
+    }
+
+    #[test]
+    fn flake_compare_with_contents() {
+        let text = Flake::new("hello");
+        assert!(text == "hello");
+        assert!(text != "hell");
+
+        let expected: &[i32] = &[1, 2, 3];
+        let slice = Flake::new(expected);
+        assert!(slice == expected);
+        assert!(slice != [3, 2, 1][..]);
+    }
+
+    #[test]
+    fn flake_as_mut_slice() {
+        let mut flake = Flake::new(&[5, -1, 3, 0][..]);
+        flake.as_mut_slice().sort_unstable();
+        assert_eq!(&*flake, &[-1, 0, 3, 5]);
+
+        flake.as_mut_slice()[0] = 9;
+        flake.as_mut_slice().iter_mut().for_each(|value| *value *= 2);
+        assert_eq!(&*flake, &[18, 0, 6, 10]);
+    }
+
+    #[test]
+    fn flake_owned_drops_once() {
+        use std::{cell::Cell, rc::Rc};
+
+        struct Counted(Rc<Cell<u32>>);
+        impl Drop for Counted {
+            fn drop(&mut self) { self.0.set(self.0.get() + 1) }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        drop(FlakeOwned::steal(Counted(drops.clone())));
+        assert_eq!(drops.get(), 1);
+
+        let owned = FlakeOwned::steal(Counted(drops.clone()));
+        let inner = owned.into_inner();
+        assert_eq!(drops.get(), 1); // Moved out, not dropped
+        drop(inner);
+        assert_eq!(drops.get(), 2);
+
+        let adopted = unsafe { FlakeOwned::from_flake(Flake::steal(Counted(drops.clone()))) };
+        drop(adopted);
+        assert_eq!(drops.get(), 3);
+    }
+
+    #[test]
+    fn flake_from_box() {
+        let boxed: Box<[u8]> = vec![1, 2, 3].into_boxed_slice();
+        let address = boxed.as_ptr();
+        let flake = Flake::from(boxed);
+        assert_eq!(flake.as_ptr() as *const u8, address); // Adopted, not reallocated
+        assert_eq!(&*flake, &[1, 2, 3]);
+
+        let text: Box<str> = "boxed".into();
+        let address = text.as_ptr();
+        let flake = Flake::<str>::from(text);
+        assert_eq!(flake.as_ptr() as *const u8, address);
+        assert_eq!(&*flake, "boxed");
+
+        assert!(Flake::<[u64]>::from(Box::<[u64]>::default()).is_empty());
+        assert_eq!(*Flake::from(Box::new(7u64)), 7);
+        assert_eq!(*Flake::<()>::from(Box::new(())), ());
+    }
+
+    #[test]
+    fn flake_owned_slice_drop_order() {
+        use std::{cell::RefCell, rc::Rc};
+
+        struct Logged(u32, Rc<RefCell<Vec<u32>>>);
+        impl Drop for Logged {
+            fn drop(&mut self) { self.1.borrow_mut().push(self.0) }
+        }
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let items: Box<[Logged]> = (0..5).map(|i| Logged(i, log.clone())).collect();
+        let owned = FlakeOwned::from(items);
+
+        assert_eq!(owned.len(), 5);
+        assert!(log.borrow().is_empty());
+        drop(owned);
+        assert_eq!(*log.borrow(), [0, 1, 2, 3, 4]); // Front to back
+    }
+
+    #[test]
+    fn flake_from_raw_with_layout() {
+        use std::alloc::{Layout, alloc};
+
+        let layout = Layout::from_size_align(32, 32).unwrap(); // Room for eight, aligned beyond `u32`
+        let flake = unsafe {
+            let raw = alloc(layout) as *mut u32;
+            raw.copy_from_nonoverlapping([1, 2, 3, 4].as_ptr(), 4);
+            Flake::from_raw_with_layout(std::ptr::slice_from_raw_parts(raw, 4), layout)
+        };
+
+        assert_eq!(&*flake, &[1, 2, 3, 4]);
+        assert_eq!(flake.layout(), layout);
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not fit the layout")]
+    fn flake_from_raw_with_layout_too_small() {
+        use std::alloc::Layout;
+
+        let value = [0u32; 4];
+        let _ = unsafe { Flake::from_raw_with_layout(&raw const value as *const [u32], Layout::new::<[u32; 2]>()) };
+    }
+
+    #[test]
+    fn flake_push_and_extend() {
+        let mut flake = Flake::new(&[1u8, 2][..]);
+        flake.push(3);
+        flake.extend_from_slice(&[4, 5, 6]);
+        flake.extend_from_slice(&[]);
+
+        assert_eq!(flake.len(), 6);
+        assert_eq!(&*flake, &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(flake.layout().size(), 6);
+
+        let mut empty = Flake::new(&[] as &[u32]);
+        empty.push(7);
+        assert_eq!(&*empty, &[7]);
+    }
+
+    #[test]
+    fn flake_with_size_limit() {
+        let flake = Flake::with_size_limit(&[1u16, 2][..], 4).unwrap();
+        assert_eq!(&*flake, &[1, 2]);
+
+        let error = Flake::with_size_limit(&[1u16, 2, 3][..], 4).err();
+        assert_eq!(error, Some(SizeLimitExceeded { size: 6, limit: 4 }));
+    }
+
+    #[test]
+    fn flake_try_push_and_extend() {
+        let mut flake = Flake::new(&[1u16][..]);
+        assert_eq!(flake.try_push(2), Ok(()));
+        assert_eq!(flake.try_extend_from_slice(&[3, 4]), Ok(()));
+        assert_eq!(flake.try_extend_from_slice(&[]), Ok(()));
+        assert_eq!(&*flake, &[1, 2, 3, 4]);
+
+
+        let error = TryReserveError::AllocError { layout: std::alloc::Layout::new::<[u16; 8]>() };
+        assert_eq!(error.to_string(), "memory allocation of 16 bytes failed");
+        let _: &dyn std::error::Error = &error;
+    }
+
+    #[test]
+    fn flake_cow() {
+        let text = "borrowed";
+        let borrowed = FlakeCow::from(text);
+        assert!(!borrowed.is_owned());
+        assert_eq!(borrowed.as_ptr(), text.as_ptr()); // No allocation, same data
+
+        let owned = borrowed.to_owned();
+        assert_ne!(owned.as_ptr() as *const u8, text.as_ptr());
+        assert_eq!(&*owned, "borrowed");
+
+        let converted = FlakeCow::from(owned);
+        let address = converted.as_ptr();
+        assert_eq!(converted.into_owned().as_ptr() as *const u8, address); // Reused, not copied
+
+        let mut cow = FlakeCow::Borrowed(&[4u16, 5][..]);
+        cow.to_mut().fill(9);
+        assert_eq!(&*cow, &[9, 9]);
+    }
+
+    #[test]
+    fn flake_len_and_is_empty() {
+        assert!(Flake::new("").is_empty());
+        assert_eq!(Flake::new("día").len(), 4);
+        assert!(Flake::new(&[0u8; 0][..]).is_empty());
+
+        let mut bytes = Flake::new(&b"ab"[..]);
+        bytes.push(b'c');
+        assert_eq!(bytes.len(), 3);
+        assert!(!bytes.is_empty());
+    }
+
+    #[test]
+    fn flake_hash_bytes() {
+        use std::hash::{DefaultHasher, Hasher};
+
+        let flake = Flake::new(&b"raw bytes"[..]);
+        let (mut by_flake, mut by_write) = (DefaultHasher::new(), DefaultHasher::new());
+        flake.hash_bytes(&mut by_flake);
+        by_write.write(b"raw bytes");
+        assert_eq!(by_flake.finish(), by_write.finish());
+    }
+
+    #[test]
+    fn flake_new_unsize() {
+        let greet = Flake::<dyn Fn(&str) -> String>::new_unsize(|name: &str| format!("hi {name}"));
+        assert_eq!(greet("flake"), "hi flake");
+
+        let mut total = 0;
+        let mut add = Flake::<dyn FnMut(u32) -> u32>::new_unsize(move |x| { total += x; total });
+        let add = unsafe { &mut *add.as_mut_ptr() };
+        assert_eq!((add(2), add(3)), (2, 5));
+    }
+
+    #[test]
+    fn flake_try_into_sized() {
+        #[derive(Debug, PartialEq, Clone, Copy)]
+        #[repr(C)]
+        struct Point { x: u16, y: u16 }
+
+        let point = Point { x: 3, y: 4 };
+        let raw = unsafe { std::slice::from_raw_parts(&point as *const Point as *const u8, 4) };
+        let bytes = Flake::new(raw);
+        assert_eq!(unsafe { bytes.try_into_sized::<Point>() }.ok(), Some(point));
+
+        let short = Flake::new(&[1u8, 2, 3][..]);
+        let short = unsafe { short.try_into_sized::<u32>() }.err().unwrap();
+        assert_eq!(&*short, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn flake_as_mut() {
+        fn fill(buffer: &mut impl AsMut<[u8]>) {
+            for (i, byte) in buffer.as_mut().iter_mut().enumerate() {
+                *byte = i as u8 * 2;
+            }
+        }
+
+        let mut flake = Flake::new(&[0u8; 4][..]);
+        fill(&mut flake);
+        assert_eq!(&*flake, &[0, 2, 4, 6]);
+
+        let read = std::io::Read::read(&mut &b"io"[..], flake.as_mut()).unwrap();
+        assert_eq!((read, &*flake), (2, &[b'i', b'o', 4, 6][..]));
+    }
+
+    #[test]
+    fn flake_from_borrowed() {
+        static GREETING: &str = "static";
+        let view: FlakeRef<str> = unsafe { Flake::from_borrowed(GREETING as *const str) };
+        assert_eq!((&**view, view.len()), ("static", 6));
+        assert_eq!(view.as_ptr(), GREETING as *const str);
+
+        let mut stack = [1u8, 2, 3, 4];
+        {
+            let view = unsafe { Flake::from_borrowed(&stack[..] as *const [u8]) };
+            let copy = view.clone();
+            assert_eq!(view.chunks_exact(2).count(), 2);
+            assert_eq!(&**copy, &[1, 2, 3, 4]);
+        }
+        stack[0] = 9; // Still owned by the stack frame, not freed by the views
+        assert_eq!(stack, [9, 2, 3, 4]);
+    }
+
+    #[test]
+    fn flake_content_fingerprint() {
+        let a = Flake::new(&b"dedup"[..]);
+        let b = Flake::new(&b"dedup"[..]);
+        assert_ne!(a.as_ptr(), b.as_ptr());
+        assert_eq!(a.content_fingerprint(), b.content_fingerprint());
+        assert_eq!(Flake::new("dedup").content_fingerprint(), a.content_fingerprint());
+        assert_ne!(Flake::new("dedupe").content_fingerprint(), a.content_fingerprint());
+    }
+
+    #[test]
+    fn flake_from_reader() {
+        use std::io::{Cursor, ErrorKind};
+
+        let mut input = Cursor::new(b"0123456789".to_vec());
+        let first = Flake::from_reader(&mut input, 4).unwrap();
+        let rest = Flake::from_reader(&mut input, 6).unwrap();
+        assert_eq!((&*first, &*rest), (&b"0123"[..], &b"456789"[..]));
+        assert_eq!(Flake::from_reader(&mut input, 0).map(|empty| empty.len()).ok(), Some(0));
+
+        let mut short = Cursor::new(b"abc");
+        let error = Flake::from_reader(&mut short, 8).err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn flake_write_cursor() {
+        use std::io::{ErrorKind, Write};
+
+        let mut bounded = FlakeWriteCursor::new(Flake::new(&[b'.'; 6][..]));
+        bounded.write_all(b"ab").unwrap();
+        bounded.write_all(b"cd").unwrap();
+        assert_eq!(bounded.write(b"efgh").unwrap(), 2); // Short write at the end
+        assert_eq!(bounded.write(b"i").unwrap(), 0);
+        assert_eq!(bounded.write_all(b"j").unwrap_err().kind(), ErrorKind::WriteZero);
+        assert_eq!((bounded.position(), &**bounded.get_ref()), (6, &b"abcdef"[..]));
+
+        let mut growing = FlakeWriteCursor::growing(Flake::new(&[b'.'; 3][..]));
+        growing.write_all(b"ab").unwrap();
+        growing.write_all(b"cdef").unwrap();
+        growing.write_all(b"g").unwrap();
+        assert_eq!(growing.position(), 7);
+        assert_eq!(&*growing.into_inner(), b"abcdefg");
+
+        let mut partial = FlakeWriteCursor::growing(Flake::new(&b"xyz"[..]));
+        partial.write_all(b"a").unwrap();
+        assert_eq!(&*partial.into_inner(), b"ayz"); // Bytes past the position are kept
+    }
+
+    #[test]
+    fn flake_as_mut_ptr_needs_exclusive_access() {
+        fn bump(flake: &mut Flake<[u32]>) {
+            unsafe { (*flake.as_mut_ptr())[0] += 1 }
+        }
+
+        let mut flake = Flake::new(&[1u32, 2][..]);
+        bump(&mut flake);
+        let mut owned = FlakeOwned::steal(String::from("a"));
+        owned.push('b'); // `DerefMut` goes through `as_mut_ptr`
+
+        assert_eq!((&*flake, owned.as_str()), (&[2, 2][..], "ab"));
+    }
 }
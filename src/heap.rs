@@ -0,0 +1,75 @@
+use std::{alloc::*, ptr::{without_provenance_mut, write_volatile}};
+
+/// Allocates memory for the given layout, aborting via [`handle_alloc_error`] on failure.
+///
+/// Zero-sized layouts never reach the allocator (which would be undefined behavior); a dangling
+/// but well-aligned pointer is returned instead, like `Box` and `Vec` do.
+#[inline(always)]
+pub(crate) unsafe fn allocate(layout: Layout) -> *mut u8 {
+    if layout.size() == 0 {
+        return without_provenance_mut(layout.align());
+    }
+
+    let raw = alloc(layout);
+    if raw.is_null() {
+        handle_alloc_error(layout);
+    }
+
+    raw
+}
+
+/// Releases memory obtained from [`allocate`] with the same layout.
+///
+/// Zero-sized layouts are skipped, since their pointer is dangling.
+#[inline(always)]
+pub(crate) unsafe fn deallocate(ptr: *mut u8, layout: Layout) {
+    if layout.size() != 0 {
+        dealloc(ptr, layout);
+    }
+}
+
+/// Whether freed `Rime` and `Flake` blocks are poisoned (debug builds or the `poison-on-free` feature).
+const POISON: bool = cfg!(any(debug_assertions, feature = "poison-on-free"));
+
+/// Overwrites a block about to be freed with `0xDE` bytes, when poisoning is enabled.
+///
+/// A stale pointer into the block then reads a recognizable pattern instead of plausible data.
+/// The writes are volatile so they are not elided as dead stores before the free.
+#[inline(always)]
+pub(crate) unsafe fn poison(ptr: *mut u8, layout: Layout) {
+    if POISON {
+        for i in 0..layout.size() {
+            write_volatile(ptr.add(i), 0xDE);
+        }
+    }
+}
+
+/// Resizes memory obtained from [`allocate`] to `new_size` bytes, keeping the alignment of `layout`.
+///
+/// Zero-sized layouts on either side are handled without reaching the allocator.
+#[inline(always)]
+pub(crate) unsafe fn reallocate(ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    let raw = try_reallocate(ptr, layout, new_size);
+    if raw.is_null() {
+        handle_alloc_error(Layout::from_size_align_unchecked(new_size, layout.align()));
+    }
+
+    raw
+}
+
+/// Like [`reallocate`], but returns null on allocation failure instead of aborting.
+///
+/// On failure the original block is left untouched and still owned by the caller.
+#[inline(always)]
+pub(crate) unsafe fn try_reallocate(ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+    if new_size == 0 {
+        deallocate(ptr, layout);
+        return without_provenance_mut(layout.align());
+    }
+
+    if layout.size() == 0 {
+        return alloc(Layout::from_size_align_unchecked(new_size, layout.align()));
+    }
+
+    realloc(ptr, layout, new_size)
+}
@@ -0,0 +1,170 @@
+use std::mem::ManuallyDrop;
+
+use crate::{Flake, heap::*, rime::*, track::*};
+
+/// A callback run with the payload just before its block is freed.
+///
+/// Implemented for every `FnOnce(&T)`, so a closure works as a hook; implement it on a named
+/// type for hooks that need to be spelled out in a field or a signature. Used by [`HookedRime`]
+/// and [`HookedFlake`] for logging, metrics, or handing the payload's contents to a cache.
+pub trait OnFree<T: ?Sized> {
+    fn on_free(self, value: &T);
+}
+
+impl<T: ?Sized, F: FnOnce(&T)> OnFree<T> for F {
+    #[inline(always)]
+    fn on_free(self, value: &T) {
+        self(value)
+    }
+}
+
+/// A [`Rime`] that runs a hook once the last of its clones is dropped, before the block is
+/// returned to the allocator.
+///
+/// Every clone carries its own copy of the hook, and exactly one of them, the one belonging
+/// to the handle that brings the count to zero, is called. `Rime` never runs payload
+/// destructors, so the hook sees the payload intact. Other plain `Rime` clones of the same
+/// allocation free it without the hook, so wrap the value before sharing it.
+///
+/// If the hook panics, the block is leaked rather than freed.
+///
+/// # Example
+/// ```
+/// use std::{cell::Cell, rc::Rc};
+/// use kroos::{HookedRime, Rime};
+///
+/// let freed = Rc::new(Cell::new(0));
+/// let log = freed.clone();
+/// let value = HookedRime::new(Rime::<u8, u32>::steal(7), move |value: &u32| log.set(*value));
+///
+/// let clone = value.clone();
+/// drop(value);
+/// assert_eq!(freed.get(), 0);
+/// drop(clone);
+/// assert_eq!(freed.get(), 7);
+/// ```
+pub struct HookedRime<C: Counter, T: ?Sized, H: OnFree<T> + Clone> {
+    rime: ManuallyDrop<Rime<C, T>>,
+    hook: ManuallyDrop<H>,
+}
+
+impl<C: Counter, T: ?Sized, H: OnFree<T> + Clone> HookedRime<C, T, H> {
+    /// Takes ownership of `rime`, calling `hook` when the last `HookedRime` clone is dropped.
+    #[inline(always)]
+    pub fn new(rime: Rime<C, T>, hook: H) -> Self {
+        Self { rime: ManuallyDrop::new(rime), hook: ManuallyDrop::new(hook) }
+    }
+}
+
+impl<C: Counter, T: ?Sized, H: OnFree<T> + Clone> Clone for HookedRime<C, T, H> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new((*self.rime).clone(), (*self.hook).clone())
+    }
+}
+
+impl<C: Counter, T: ?Sized, H: OnFree<T> + Clone> std::ops::Deref for HookedRime<C, T, H> {
+    type Target = Rime<C, T>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Rime<C, T> {
+        &self.rime
+    }
+}
+
+impl<C: Counter, T: ?Sized, H: OnFree<T> + Clone> Drop for HookedRime<C, T, H> {
+    fn drop(&mut self) {
+        let rime = &*self.rime;
+        let counter_ptr = rime.counter_ptr();
+
+        unsafe {
+            let hook = ManuallyDrop::take(&mut self.hook);
+            if !(*counter_ptr).decrement() { return }
+
+            hook.on_free(rime);
+
+            if (*counter_ptr).release() {
+                let layout = rime.layout();
+                poison(counter_ptr.cast(), layout);
+                deallocate(counter_ptr.cast(), layout);
+                track_free();
+            }
+        }
+    }
+}
+
+/// A [`Flake`] that runs a hook on its payload right before freeing it.
+///
+/// `Flake` never runs payload destructors, so the hook sees the payload intact.
+///
+/// # Example
+/// ```
+/// use std::cell::Cell;
+/// use kroos::{Flake, HookedFlake};
+///
+/// let seen = Cell::new(0);
+/// drop(HookedFlake::new(Flake::new(&[1u8, 2, 3][..]), |bytes: &[u8]| seen.set(bytes.len())));
+/// assert_eq!(seen.get(), 3);
+/// ```
+pub struct HookedFlake<T: ?Sized, H: OnFree<T>> {
+    flake: ManuallyDrop<Flake<T>>,
+    hook: ManuallyDrop<H>,
+}
+
+impl<T: ?Sized, H: OnFree<T>> HookedFlake<T, H> {
+    /// Takes ownership of `flake`, calling `hook` when it is dropped.
+    #[inline(always)]
+    pub fn new(flake: Flake<T>, hook: H) -> Self {
+        Self { flake: ManuallyDrop::new(flake), hook: ManuallyDrop::new(hook) }
+    }
+}
+
+impl<T: ?Sized, H: OnFree<T>> std::ops::Deref for HookedFlake<T, H> {
+    type Target = Flake<T>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Flake<T> {
+        &self.flake
+    }
+}
+
+impl<T: ?Sized, H: OnFree<T>> Drop for HookedFlake<T, H> {
+    fn drop(&mut self) {
+        unsafe {
+            ManuallyDrop::take(&mut self.hook).on_free(&self.flake);
+            ManuallyDrop::drop(&mut self.flake);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc, sync::atomic::AtomicUsize};
+    use super::*;
+
+    #[test]
+    fn hook_fires_once_on_final_drop() {
+        let freed = Rc::new(RefCell::new(Vec::new()));
+        let log = freed.clone();
+        let hook = move |value: &str| log.borrow_mut().push(value.to_string());
+
+        let rime = HookedRime::new(Rime::<AtomicUsize, str>::new("payload"), hook);
+        let clones = [rime.clone(), rime.clone()];
+        assert_eq!(rime.strong_count(), 3);
+
+        drop(rime);
+        assert!(freed.borrow().is_empty());
+        drop(clones);
+        assert_eq!(*freed.borrow(), ["payload"]);
+    }
+
+    #[test]
+    fn flake_hook_sees_payload() {
+        let freed = RefCell::new(None);
+        let flake = HookedFlake::new(Flake::new("flake"), |value: &str| *freed.borrow_mut() = Some(value.to_string()));
+        assert_eq!(&**flake, "flake");
+
+        drop(flake);
+        assert_eq!(freed.borrow().as_deref(), Some("flake"));
+    }
+}
@@ -0,0 +1,144 @@
+use std::collections::HashSet;
+
+use crate::{Counter, Rime, RimeValue};
+
+/// A table that hands out one shared [`Rime`] per distinct string.
+///
+/// The first [`RimeInterner::intern`] of a string allocates it; later calls with equal contents
+/// return a clone of the same `Rime`, so repeated identifiers, tags or keys share a single
+/// allocation. Entries are stored as [`RimeValue`]s, which hash by content and can be looked up
+/// with a plain `&str`, so the table keeps no second copy of each string.
+///
+/// The interner holds one reference to every entry until it is dropped or cleared. Since
+/// interned `Rime`s are unique per content, they can be compared and hashed by address (see
+/// [`RimeIdentity`](crate::RimeIdentity)).
+///
+/// # Example
+/// ```
+/// use kroos::RimeInterner;
+///
+/// let mut interner = RimeInterner::<u32>::new();
+/// let a = interner.intern("token");
+/// let b = interner.intern("token");
+///
+/// assert_eq!(a, b); // Same allocation
+/// assert_eq!(interner.len(), 1);
+/// ```
+pub struct RimeInterner<C: Counter> {
+    entries: HashSet<RimeValue<C, str>>,
+}
+
+impl<C: Counter> RimeInterner<C> {
+    /// Creates an empty interner.
+    #[inline]
+    pub fn new() -> Self {
+        Self { entries: HashSet::new() }
+    }
+
+    /// Creates an empty interner with room for at least `capacity` distinct strings before the
+    /// table has to grow.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { entries: HashSet::with_capacity(capacity) }
+    }
+
+    /// Reserves room for at least `additional` more distinct strings, avoiding repeated rehashes
+    /// while a large table is filled.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows `usize`, like [`HashSet::reserve`].
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.entries.reserve(additional)
+    }
+
+    /// Returns the number of distinct strings the table can hold without growing.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    /// Returns the shared `Rime` for `value`, allocating it on first use.
+    pub fn intern(&mut self, value: &str) -> Rime<C, str> {
+        if let Some(entry) = self.entries.get(value) {
+            return entry.0.clone();
+        }
+
+        let rime = Rime::new(value);
+        self.entries.insert(RimeValue(rime.clone()));
+        rime
+    }
+
+    /// Returns the shared `Rime` for `value` if it was already interned, without allocating.
+    #[inline]
+    pub fn get(&self, value: &str) -> Option<Rime<C, str>> {
+        self.entries.get(value).map(|entry| entry.0.clone())
+    }
+
+    /// Returns the number of distinct strings in the table.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing has been interned.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drops the table's reference to every entry. `Rime`s handed out earlier stay valid.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear()
+    }
+}
+
+impl<C: Counter> Default for RimeInterner<C> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use super::*;
+
+    #[test]
+    fn interner_shares_equal_strings() {
+        let mut interner = RimeInterner::<AtomicUsize>::new();
+        let first = interner.intern("shared");
+        let second = interner.intern(&String::from("shared"));
+        let other = interner.intern("other");
+
+        assert_eq!(first.as_ptr(), second.as_ptr());
+        assert_ne!(first.as_ptr(), other.as_ptr());
+        assert_eq!(first.strong_count(), 3); // Two handles plus the table's
+        assert_eq!(interner.len(), 2);
+
+        assert_eq!(interner.get("other"), Some(other));
+        assert_eq!(interner.get("missing"), None);
+
+        interner.clear();
+        assert!(interner.is_empty());
+        assert_eq!((&*first, first.strong_count().get()), ("shared", 2));
+    }
+
+    #[test]
+    fn interner_capacity() {
+        let mut interner = RimeInterner::<AtomicUsize>::with_capacity(64);
+        let capacity = interner.capacity();
+        assert!(capacity >= 64);
+
+        for i in 0..64 {
+            interner.intern(&i.to_string());
+        }
+        assert_eq!(interner.capacity(), capacity); // Filled without growing
+
+        interner.reserve(100);
+        assert!(interner.capacity() >= interner.len() + 100);
+        assert!(RimeInterner::<u8>::new().capacity() < 64);
+    }
+}
@@ -0,0 +1,164 @@
+use std::{borrow::Borrow, hash::{Hash, Hasher}};
+
+use crate::{Counter, Rime};
+
+/// A [`Rime`] keyed by the identity of its allocation.
+///
+/// Two keys are equal only if they share one allocation, and hashing uses the address
+/// ([`Rime::hash_by_address`]). Lookups are a pointer comparison regardless of payload size,
+/// which suits interning tables where every distinct value is allocated once. Separate
+/// allocations with equal contents are distinct keys.
+///
+/// A plain `Rime` already compares by identity but hashes by content; that is consistent,
+/// only slower to hash. `RimeIdentity` makes the identity semantics explicit.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use kroos::{Rime, RimeIdentity};
+///
+/// let name = Rime::<u8, str>::new("name");
+/// let mut map = HashMap::new();
+/// map.insert(RimeIdentity(name.clone()), 1);
+///
+/// assert_eq!(map.get(&RimeIdentity(name)), Some(&1));
+/// assert_eq!(map.get(&RimeIdentity(Rime::new("name"))), None); // Another allocation
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct RimeIdentity<C: Counter, T: ?Sized>(pub Rime<C, T>);
+
+/// A [`Rime`] keyed by the contents of its payload.
+///
+/// Equality and hashing both forward to `T`, so equal values are the same key even across
+/// separate allocations, and maps can be queried with a plain `&T` through [`Borrow`]. The
+/// cost is a full comparison and hash of the payload on every lookup.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use kroos::{Rime, RimeValue};
+///
+/// let mut map = HashMap::new();
+/// map.insert(RimeValue(Rime::<u8, str>::new("name")), 1);
+///
+/// assert_eq!(map.get("name"), Some(&1));
+/// assert_eq!(map.get(&RimeValue(Rime::new("name"))), Some(&1));
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct RimeValue<C: Counter, T: ?Sized>(pub Rime<C, T>);
+
+impl<C: Counter, T: ?Sized> Clone for RimeIdentity<C, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<C: Counter, T: ?Sized> Eq for RimeIdentity<C, T> { }
+impl<C: Counter, T: ?Sized> PartialEq for RimeIdentity<C, T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C: Counter, T: ?Sized> Hash for RimeIdentity<C, T> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_by_address(state)
+    }
+}
+
+impl<C: Counter, T: ?Sized> From<Rime<C, T>> for RimeIdentity<C, T> {
+    #[inline(always)]
+    fn from(rime: Rime<C, T>) -> Self {
+        Self(rime)
+    }
+}
+
+impl<C: Counter, T: ?Sized> std::ops::Deref for RimeIdentity<C, T> {
+    type Target = Rime<C, T>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<C: Counter, T: ?Sized> Clone for RimeValue<C, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<C: Counter, T: ?Sized + Eq> Eq for RimeValue<C, T> { }
+impl<C: Counter, T: ?Sized + PartialEq> PartialEq for RimeValue<C, T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl<C: Counter, T: ?Sized + Hash> Hash for RimeValue<C, T> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (*self.0).hash(state)
+    }
+}
+
+impl<C: Counter, T: ?Sized> Borrow<T> for RimeValue<C, T> {
+    #[inline(always)]
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<C: Counter, T: ?Sized> From<Rime<C, T>> for RimeValue<C, T> {
+    #[inline(always)]
+    fn from(rime: Rime<C, T>) -> Self {
+        Self(rime)
+    }
+}
+
+impl<C: Counter, T: ?Sized> std::ops::Deref for RimeValue<C, T> {
+    type Target = Rime<C, T>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::*;
+
+    #[test]
+    fn identity_keys() {
+        let first = Rime::<u8, str>::new("key");
+        let twin = Rime::<u8, str>::new("key");
+
+        let mut map = HashMap::new();
+        map.insert(RimeIdentity(first.clone()), "first");
+        map.insert(RimeIdentity(twin.clone()), "twin");
+
+        assert_eq!(map.len(), 2); // Equal contents, distinct allocations
+        assert_eq!(map[&RimeIdentity(first)], "first");
+        assert_eq!(map[&RimeIdentity(twin)], "twin");
+    }
+
+    #[test]
+    fn value_keys() {
+        let mut map = HashMap::new();
+        map.insert(RimeValue::from(Rime::<u8, [u32]>::new(&[1, 2])), "first");
+        map.insert(RimeValue::from(Rime::<u8, [u32]>::new(&[1, 2])), "twin");
+
+        assert_eq!(map.len(), 1); // The second insert replaced the first
+        assert_eq!(map.get(&[1, 2][..]), Some(&"twin"));
+        assert_eq!(map.get(&[2, 1][..]), None);
+    }
+}
@@ -13,10 +13,36 @@
 // along with this program.  If not, see <https://www.gnu.org/licenses/>.
 
 #![allow(internal_features, unsafe_op_in_unsafe_fn)]
-#![feature(core_intrinsics, ptr_metadata)]
+#![feature(layout_for_ptr, ptr_metadata, unsize)]
 
+mod adopted;
+mod arena;
+mod counters;
 mod flake;
+mod heap;
+mod hook;
+mod interner;
+mod key;
+mod pool;
 mod rime;
+mod secret;
+mod small;
+mod track;
+mod weak;
+mod window;
 
+pub use adopted::*;
+pub use arena::*;
+pub use counters::*;
 pub use flake::*;
-pub use rime::*;
\ No newline at end of file
+pub use hook::*;
+pub use interner::*;
+pub use key::*;
+pub use pool::*;
+pub use rime::*;
+pub use secret::*;
+pub use small::*;
+pub use weak::*;
+pub use window::*;
+#[cfg(feature = "track-allocations")]
+pub use track::live_allocations;
\ No newline at end of file
@@ -0,0 +1,139 @@
+use std::{alloc::Layout, marker::PhantomData, mem::forget, ptr::*};
+
+use crate::{heap::*, rime::*, track::*};
+
+/// A free-list of `[ C | padding | T ]` blocks that recycles [`Rime`] allocations.
+///
+/// Churny workloads (e.g. interning) that repeatedly create and discard `Rime`s of the same
+/// type can hand uniquely owned values back with [`RimePool::recycle`]. The block is kept on
+/// the free-list and reused by the next [`RimePool::steal`], avoiding a round-trip through the
+/// global allocator.
+///
+/// Blocks still held by the pool are released when the pool is dropped.
+///
+/// # Example
+/// ```
+/// use kroos::RimePool;
+///
+/// let mut pool = RimePool::<u8, u64>::new();
+/// let first = pool.steal(1);
+/// let address = first.as_ptr();
+///
+/// assert_eq!(pool.recycle(first), Ok(1));
+/// assert_eq!(pool.steal(2).as_ptr(), address); // Same block, no new allocation
+/// ```
+pub struct RimePool<C: Counter, T> {
+    _marker: PhantomData<(C, T)>,
+    free: Vec<*mut C>,
+}
+
+impl<C: Counter, T> RimePool<C, T> {
+    /// Creates an empty pool. No memory is allocated until blocks are recycled.
+    #[inline]
+    pub fn new() -> Self {
+        Self { _marker: PhantomData, free: Vec::new() }
+    }
+
+    /// Returns the number of blocks waiting on the free-list.
+    #[inline(always)]
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Moves a value into a `Rime`, reusing a recycled block when one is available.
+    ///
+    /// Falls back to a fresh allocation (like [`Rime::steal`]) when the free-list is empty.
+    pub fn steal(&mut self, value: T) -> Rime<C, T> {
+        if self.free.is_empty() {
+            return Rime::steal(value);
+        }
+
+        // Construct the counter before taking a block, so a panic leaves it on the free-list
+        let counter = C::new();
+        let counter_ptr = self.free.pop().unwrap();
+
+        unsafe {
+            let (_, offset) = block_layout::<C>(Layout::new::<T>());
+            write(counter_ptr, counter);
+
+            let data_ptr = counter_ptr.cast::<u8>().add(offset) as *mut T;
+            write(data_ptr, value);
+
+            Rime::from_raw(counter_ptr, data_ptr)
+        }
+    }
+
+    /// Takes the payload out of a uniquely owned `Rime` and keeps its block for reuse.
+    ///
+    /// # Errors
+    /// Returns the `Rime` untouched if other clones still share the allocation.
+    pub fn recycle(&mut self, rime: Rime<C, T>) -> Result<T, Rime<C, T>> {
+        if !rime.is_unique() { return Err(rime) }
+
+        unsafe {
+            let value = read(rime.as_ptr());
+            let counter_ptr = rime.counter_ptr();
+            forget(rime);
+
+            drop_in_place(counter_ptr);
+            self.free.push(counter_ptr);
+            Ok(value)
+        }
+    }
+}
+
+impl<C: Counter, T> Default for RimePool<C, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Counter, T> Drop for RimePool<C, T> {
+    fn drop(&mut self) {
+        let (layout, _) = block_layout::<C>(Layout::new::<T>());
+        for counter_ptr in self.free.drain(..) {
+            unsafe { deallocate(counter_ptr.cast(), layout) }
+            track_free();
+        }
+    }
+}
+
+unsafe impl<C: Counter + Send, T: Send> Send for RimePool<C, T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use super::*;
+
+    #[test]
+    fn pool_reuses_blocks() {
+        let mut pool = RimePool::<AtomicUsize, [u64; 4]>::new();
+        let first = pool.steal([0; 4]);
+        let address = first.as_ptr();
+        assert_eq!(pool.recycle(first), Ok([0; 4]));
+
+        for i in 0..1_000 {
+            let rime = pool.steal([i; 4]);
+            assert_eq!(rime.as_ptr(), address); // Never hits the global allocator
+            assert_eq!(rime.strong_count(), 1);
+            assert_eq!(pool.recycle(rime), Ok([i; 4]));
+        }
+
+        assert_eq!(pool.available(), 1);
+    }
+
+    #[test]
+    fn pool_refuses_shared() {
+        let mut pool = RimePool::<u8, u32>::new();
+        let rime = pool.steal(7);
+        let cloned = rime.clone();
+
+        let rime = pool.recycle(rime).unwrap_err();
+        assert_eq!(pool.available(), 0);
+
+        drop(cloned);
+        assert_eq!(pool.recycle(rime), Ok(7));
+        assert_eq!(pool.available(), 1);
+    }
+}
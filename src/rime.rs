@@ -1,4 +1,6 @@
-use std::{marker::PhantomData, mem::size_of_val, hash::Hash, sync::atomic::*, alloc::*, ptr::*};
+use std::{convert::Infallible, marker::PhantomData, mem::size_of_val, hash::Hash, sync::atomic::*, alloc::*, ptr::*, ffi::{CStr, c_char}};
+
+use crate::{AdoptedRime, Flake, FlakeRaw, Noop, heap::*, track::*};
 
 /// A trait for defining a reference-counting strategy.
 ///
@@ -9,7 +11,31 @@ use std::{marker::PhantomData, mem::size_of_val, hash::Hash, sync::atomic::*, al
 /// # Safety
 /// Implementors must ensure:
 /// - `increment()` increases the count.
+/// - `increment_by(n)` increases the count by `n`, as if `increment()` were called `n` times.
+/// - `decrement_by(n)` decreases the count by `n` and returns `true` if this brought it to zero
+///   (never for `n == 0`); atomic counters apply the same fence as `decrement()`.
 /// - `decrement()` decreases it and returns `true` if the count reached zero.
+/// - `try_increment_nonzero()` only increases the count if it is not already zero, so a value that
+///   is being torn down can never be resurrected (e.g. by a weak reference upgrade).
+/// - `count()` returns the current count widened to `usize`, saturating at `usize::MAX` for
+///   wider integers, and never panics; atomic counters read it with `Acquire` ordering so it
+///   can back uniqueness checks.
+/// - `count_relaxed()` returns the same value as `count()` with the weakest ordering available
+///   (`Relaxed` for atomics). It is meant for diagnostics and single-threaded fast paths, and must
+///   not be used to decide whether the payload may be mutated by another thread.
+/// - `is_unique()` returns `true` only if no other handle (strong or weak) can reach the payload.
+/// - `release()` is called once the count reached zero and returns `true` if the block may be
+///   deallocated. Counters that track weak references use it to defer the free.
+/// - `compare_exchange(current, new)` sets the count to `new` only if it equals `current`,
+///   returning the previous count as `Ok`, or the actual count as `Err` without changing it.
+///   Atomic counters do this in one step with `AcqRel` ordering on success and `Acquire` on
+///   failure; the default implementation emulates it with `count()` and the batch methods, which
+///   is only correct for counters that are not shared between threads.
+/// - `raw_value()` (only with the `test-utils` feature, or in this crate's own tests) returns the
+///   stored count as is, for asserting exact refcount transitions in single-threaded tests. It
+///   makes no ordering promises and must not be used outside tests.
+/// - `reset()` puts the counter back in the state returned by `new()`. It is only called on a
+///   block that no other handle can reach, e.g. one being recycled.
 /// - Overflow and underflow are either prevented or result in a panic.
 ///
 /// Atomic counters must provide proper memory ordering for safe concurrent use.
@@ -22,7 +48,23 @@ use std::{marker::PhantomData, mem::size_of_val, hash::Hash, sync::atomic::*, al
 pub trait Counter: Sized {
     fn new() -> Self;
     fn increment(&mut self);
+    #[inline(always)] fn increment_by(&mut self, n: usize) { (0..n).for_each(|_| self.increment()) }
     fn decrement(&mut self) -> bool;
+    #[inline(always)] fn decrement_by(&mut self, n: usize) -> bool { (0..n).fold(false, |_, _| self.decrement()) }
+    fn try_increment_nonzero(&mut self) -> bool;
+    fn count(&self) -> usize;
+    #[inline(always)] fn count_relaxed(&self) -> usize { self.count() }
+    #[cfg(any(test, feature = "test-utils"))]
+    #[inline(always)] fn raw_value(&self) -> usize { self.count_relaxed() }
+    #[inline(always)] fn is_unique(&self) -> bool { self.count() == 1 }
+    #[inline(always)] fn release(&mut self) -> bool { true }
+    #[inline(always)] fn reset(&mut self) { *self = Self::new() }
+    #[inline(always)] fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> {
+        let count = self.count();
+        if count != current { return Err(count) }
+        if new > count { self.increment_by(new - count) } else { self.decrement_by(count - new); }
+        Ok(count)
+    }
 }
 
 macro_rules! impl_ref_count_for_primitive {
@@ -30,44 +72,380 @@ macro_rules! impl_ref_count_for_primitive {
         $(
             impl Counter for $t {
                 #[inline(always)] fn new() -> Self { 1 }
-                #[inline(always)] fn increment(&mut self) { *self += 1 }
+                #[inline(always)] fn increment(&mut self) { *self = self.checked_add(1).expect("RefCount overflow") }
+                #[inline(always)] fn increment_by(&mut self, n: usize) {
+                    *self = n.try_into().ok().and_then(|n| self.checked_add(n)).expect("RefCount overflow");
+                }
                 #[inline(always)] fn decrement(&mut self) -> bool {
                     *self -= 1;
                     *self == 0
                 }
+                #[inline(always)] fn decrement_by(&mut self, n: usize) -> bool {
+                    *self = n.try_into().ok().and_then(|n| self.checked_sub(n)).expect("RefCount underflow");
+                    n != 0 && *self == 0
+                }
+                #[inline(always)] fn try_increment_nonzero(&mut self) -> bool {
+                    if *self == 0 { return false }
+                    *self = self.checked_add(1).expect("RefCount overflow");
+                    true
+                }
+                #[inline(always)] fn count(&self) -> usize { usize::try_from(*self).unwrap_or(usize::MAX) }
+                #[inline(always)] fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> {
+                    let count = self.count();
+                    if count != current { return Err(count) }
+                    *self = new.try_into().expect("RefCount overflow");
+                    Ok(count)
+                }
             }
 
             impl Counter for std::cell::Cell<$t> {
                 #[inline(always)] fn new() -> Self { std::cell::Cell::new(1) }
                 #[inline(always)] fn increment(&mut self) { self.set(self.get().checked_add(1).expect("RefCount overflow")); }
+                #[inline(always)] fn increment_by(&mut self, n: usize) {
+                    self.set(n.try_into().ok().and_then(|n| self.get().checked_add(n)).expect("RefCount overflow"));
+                }
                 #[inline(always)] fn decrement(&mut self) -> bool {
                     let value = self.get().checked_sub(1).expect("RefCount underflow");
                     self.set(value);
                     value == 0
                 }
+                #[inline(always)] fn decrement_by(&mut self, n: usize) -> bool {
+                    let value = n.try_into().ok().and_then(|n| self.get().checked_sub(n)).expect("RefCount underflow");
+                    self.set(value);
+                    n != 0 && value == 0
+                }
+                #[inline(always)] fn try_increment_nonzero(&mut self) -> bool {
+                    let value = self.get();
+                    if value == 0 { return false }
+                    self.set(value.checked_add(1).expect("RefCount overflow"));
+                    true
+                }
+                #[inline(always)] fn count(&self) -> usize { usize::try_from(self.get()).unwrap_or(usize::MAX) }
+                #[inline(always)] fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> {
+                    let count = self.count();
+                    if count != current { return Err(count) }
+                    self.set(new.try_into().expect("RefCount overflow"));
+                    Ok(count)
+                }
             }
         )*
     };
 }
 
+/// Atomic counters stop at half their range, like `Arc`: an increment is a single `fetch_add`
+/// checked afterwards, and the headroom above the limit absorbs concurrent increments until a
+/// refused one is undone, so the count never wraps.
 macro_rules! impl_ref_count_for_atomic {
-    ($($atomic:ty),*) => {
+    ($($atomic:ty => $int:ty),*) => {
         $(
             impl Counter for $atomic {
                 #[inline(always)] fn new() -> Self { <$atomic>::new(1) }
-                #[inline(always)] fn increment(&mut self) { self.fetch_add(1, Ordering::Release); }
+                #[inline(always)] fn increment(&mut self) {
+                    self.increment_by(1)
+                }
+                #[inline(always)] fn increment_by(&mut self, n: usize) {
+                    const LIMIT: $int = <$int>::MAX / 2;
+                    let Some(n) = n.try_into().ok().filter(|&n| n <= LIMIT) else { panic!("RefCount overflow") };
+                    let previous = self.fetch_add(n, Ordering::Relaxed);
+                    if previous > LIMIT - n {
+                        self.fetch_sub(n, Ordering::Relaxed);
+                        panic!("RefCount overflow");
+                    }
+                }
                 #[inline(always)] fn decrement(&mut self) -> bool {
                     if self.fetch_sub(1, Ordering::Release) == 1 {
                         fence(Ordering::Acquire); true 
                     } else { false }
                 }
+                #[inline(always)] fn decrement_by(&mut self, n: usize) -> bool {
+                    if n == 0 { return false }
+                    // Checked before the store, so other handles never see a wrapped count
+                    let n = n.try_into().expect("RefCount underflow");
+                    let mut current = self.load(Ordering::Relaxed);
+                    loop {
+                        let next = current.checked_sub(n).expect("RefCount underflow");
+                        match self.compare_exchange_weak(current, next, Ordering::Release, Ordering::Relaxed) {
+                            Ok(_) if next == 0 => { fence(Ordering::Acquire); return true }
+                            Ok(_) => return false,
+                            Err(actual) => current = actual,
+                        }
+                    }
+                }
+                #[inline(always)] fn try_increment_nonzero(&mut self) -> bool {
+                    let mut current = self.load(Ordering::Relaxed);
+                    loop {
+                        if current == 0 { return false }
+                        assert!(current < <$int>::MAX / 2, "RefCount overflow");
+                        let next = current + 1;
+                        match self.compare_exchange_weak(current, next, Ordering::Acquire, Ordering::Relaxed) {
+                            Ok(_) => return true,
+                            Err(actual) => current = actual,
+                        }
+                    }
+                }
+                #[inline(always)] fn count(&self) -> usize { usize::try_from(self.load(Ordering::Acquire)).unwrap_or(usize::MAX) }
+                #[inline(always)] fn count_relaxed(&self) -> usize { usize::try_from(self.load(Ordering::Relaxed)).unwrap_or(usize::MAX) }
+                #[inline(always)] fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> {
+                    let Some(expected) = current.try_into().ok() else { return Err(self.count()) };
+                    let new = new.try_into().expect("RefCount overflow");
+                    <$atomic>::compare_exchange(self, expected, new, Ordering::AcqRel, Ordering::Acquire)
+                        .map(|count| usize::try_from(count).unwrap_or(usize::MAX))
+                        .map_err(|count| usize::try_from(count).unwrap_or(usize::MAX))
+                }
             }
         )*
     };
 }
 
 impl_ref_count_for_primitive!(u8, u16, u32, u64, u128, usize);
-impl_ref_count_for_atomic!(AtomicU8, AtomicU16, AtomicU32, AtomicU64, AtomicUsize);
+impl_ref_count_for_atomic!(AtomicU8 => u8, AtomicU16 => u16, AtomicU32 => u32, AtomicU64 => u64, AtomicUsize => usize);
+
+/// The largest counter alignment a `Rime` accepts, checked at compile time.
+///
+/// Counters only hold an integer or two; anything aligned beyond a page is almost certainly a
+/// mistake and would waste most of every block on padding.
+pub(crate) const MAX_COUNTER_ALIGN: usize = 4096;
+
+/// Hashes `bytes` with 64-bit FNV-1a, a fixed function with no per-process seed.
+///
+/// Backs the `content_fingerprint` methods, whose results must be stable across runs.
+pub(crate) fn fingerprint(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
+/// Computes the layout of a `[ C | padding | T ]` block and the offset of `T` within it.
+#[inline(always)]
+pub(crate) fn block_layout<C>(value: Layout) -> (Layout, usize) {
+    let (layout, offset) = Layout::new::<C>().extend(value).expect("Rime layout overflow");
+    (layout.pad_to_align(), offset)
+}
+
+/// Allocates a `[ C | padding | T ]` block for a payload of the given layout and initializes the counter.
+///
+/// Returns the counter pointer and a pointer to the uninitialized payload.
+///
+/// The counter is constructed before allocating, so a panicking `C::new` leaves nothing behind.
+#[inline(always)]
+pub(crate) unsafe fn allocate_block<C: Counter>(value: Layout) -> (*mut C, *mut u8) {
+    const { assert!(align_of::<C>() <= MAX_COUNTER_ALIGN, "Rime counters must not be aligned to more than 4096 bytes") };
+
+    let (layout, offset) = block_layout::<C>(value);
+    let counter = C::new();
+
+    let raw = allocate(layout);
+    let counter_ptr = raw as *mut C;
+    write(counter_ptr, counter);
+    track_alloc();
+
+    (counter_ptr, raw.add(offset))
+}
+
+/// An in-progress `[ C | padding | [T] ]` block whose elements are written one at a time.
+///
+/// If the builder is dropped before [`SliceBuilder::finish`] (an early error or a panic),
+/// the initialized elements are dropped and the block is released.
+pub(crate) struct SliceBuilder<C: Counter, T> {
+    counter_ptr: *mut C,
+    data_ptr: *mut T,
+    len: usize,
+    capacity: usize,
+}
+
+impl<C: Counter, T> SliceBuilder<C, T> {
+    /// Allocates a block with room for `capacity` elements.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        let layout = Layout::array::<T>(capacity).expect("Rime layout overflow");
+        let (counter_ptr, data_ptr) = unsafe { allocate_block::<C>(layout) };
+        Self { counter_ptr, data_ptr: data_ptr as *mut T, len: 0, capacity }
+    }
+
+    /// Returns `true` if every reserved slot has been written.
+    #[inline(always)]
+    pub(crate) fn is_full(&self) -> bool {
+        self.len == self.capacity
+    }
+
+    /// Writes the next element.
+    ///
+    /// # Panics
+    /// Panics if the builder is already full.
+    #[inline]
+    pub(crate) fn push(&mut self, value: T) {
+        assert!(!self.is_full(), "SliceBuilder capacity exceeded");
+        unsafe { write(self.data_ptr.add(self.len), value) }
+        self.len += 1;
+    }
+
+    /// Reallocates the block down to room for `capacity` elements, never below the number
+    /// already written. The counter prefix is moved along with the data by `realloc`.
+    pub(crate) fn shrink_to(&mut self, capacity: usize) {
+        let capacity = capacity.max(self.len);
+        if capacity >= self.capacity {
+            return;
+        }
+
+        unsafe {
+            let (old, offset) = block_layout::<C>(Layout::array::<T>(self.capacity).unwrap_unchecked());
+            let (new, _) = block_layout::<C>(Layout::array::<T>(capacity).unwrap_unchecked());
+            let raw = reallocate(self.counter_ptr.cast(), old, new.size());
+
+            self.counter_ptr = raw as *mut C;
+            self.data_ptr = raw.add(offset) as *mut T;
+            self.capacity = capacity;
+        }
+    }
+
+    /// Turns the written elements into a `Rime`, shrinking the block if it was over-allocated.
+    pub(crate) fn finish(mut self) -> Rime<C, [T]> {
+        self.shrink_to(self.len);
+        let this = std::mem::ManuallyDrop::new(self);
+        Rime::from_raw(this.counter_ptr, slice_from_raw_parts(this.data_ptr, this.len))
+    }
+}
+
+impl<C: Counter, T> Drop for SliceBuilder<C, T> {
+    fn drop(&mut self) {
+        unsafe {
+            drop_in_place(slice_from_raw_parts_mut(self.data_ptr, self.len));
+            drop_in_place(self.counter_ptr);
+
+            let (layout, _) = block_layout::<C>(Layout::array::<T>(self.capacity).unwrap_unchecked());
+            deallocate(self.counter_ptr.cast(), layout);
+            track_free();
+        }
+    }
+}
+
+/// The error returned by in-place operations that require a uniquely owned [`Rime`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotUnique;
+
+impl std::fmt::Display for NotUnique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("the Rime is shared with other clones")
+    }
+}
+
+impl std::error::Error for NotUnique {}
+
+/// The error returned by [`Rime::with_size_limit`] and [`Flake::with_size_limit`] when a
+/// payload is larger than the caller's bound. Nothing is allocated in that case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeLimitExceeded {
+    /// The number of bytes the allocation would have needed (saturating at `usize::MAX`).
+    pub size: usize,
+    /// The bound that was exceeded.
+    pub limit: usize,
+}
+
+impl std::fmt::Display for SizeLimitExceeded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "allocation of {} bytes exceeds the limit of {} bytes", self.size, self.limit)
+    }
+}
+
+impl std::error::Error for SizeLimitExceeded {}
+
+/// The error returned by [`Rime::try_get_mut`], telling apart the two reasons an element cannot be
+/// borrowed mutably.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MutError {
+    /// The index is not below the length of the slice.
+    OutOfBounds { index: usize, len: usize },
+    /// The allocation is shared with other clones.
+    NotUnique,
+}
+
+impl std::fmt::Display for MutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OutOfBounds { index, len } => write!(f, "index {index} is out of bounds for a slice of length {len}"),
+            Self::NotUnique => NotUnique.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for MutError {}
+
+impl From<NotUnique> for MutError {
+    #[inline(always)]
+    fn from(_: NotUnique) -> Self {
+        Self::NotUnique
+    }
+}
+
+/// A snapshot of the strong count of a [`Rime`], returned by [`Rime::strong_count`].
+///
+/// The value is read with `Relaxed` ordering and describes the count at one instant only: with
+/// atomic counters, other threads may clone or drop the moment after it is read. Use it for
+/// diagnostics, logging and single-threaded checks, never to decide that shared memory may be
+/// mutated; [`Rime::is_unique`] and the `NotUnique`-returning methods do that check soundly.
+///
+/// It compares directly with `usize`, and [`StrongCount::get`] unwraps the number.
+///
+/// # Example
+/// ```
+/// use kroos::Rime;
+///
+/// let rime = Rime::<u8, str>::new("counted");
+/// assert!(rime.strong_count().is_one());
+///
+/// let clone = rime.clone();
+/// assert_eq!(rime.strong_count(), 2);
+/// assert!(clone.strong_count() > 1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrongCount(usize);
+
+impl StrongCount {
+    /// Returns the count as a plain number.
+    #[inline(always)]
+    pub fn get(self) -> usize {
+        self.0
+    }
+
+    /// Returns `true` if exactly one `Rime` shared the allocation when the count was read.
+    #[inline(always)]
+    pub fn is_one(self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl PartialEq<usize> for StrongCount {
+    #[inline(always)]
+    fn eq(&self, other: &usize) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialOrd<usize> for StrongCount {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &usize) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl From<StrongCount> for usize {
+    #[inline(always)]
+    fn from(count: StrongCount) -> Self {
+        count.0
+    }
+}
+
+impl std::fmt::Debug for StrongCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl std::fmt::Display for StrongCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
 
 /// A compact reference-counted pointer for unsized or immutable data.
 ///
@@ -76,11 +454,15 @@ impl_ref_count_for_atomic!(AtomicU8, AtomicU16, AtomicU32, AtomicU64, AtomicUsiz
 ///
 /// The pointer layout is:
 /// ```text
-/// [ C | T ]
-///   |   |____ user data (T)
-///   |________ reference counter (C)
+/// [ C | padding | T ]
+///   |      |      |____ user data (T)
+///   |      |___________ zero or more bytes so that T is aligned
+///   |__________________ reference counter (C), at the start of the block
 /// ```
 ///
+/// The payload lives at `size_of::<C>()` rounded up to the alignment of `T`, which is the
+/// offset produced by `Layout::new::<C>().extend(Layout::for_value(value))`.
+///
 /// # Features
 /// - Configurable: users choose atomic or non-atomic reference counting
 /// - Efficient: counter and data are stored in a single allocation
@@ -115,7 +497,6 @@ impl_ref_count_for_atomic!(AtomicU8, AtomicU16, AtomicU32, AtomicU64, AtomicUsiz
 /// | Atomic counters       | ✅ (`Arc`)   | ✅ (via `Atomic*`) |
 /// | Inline allocation     | ❌           | ✅                 |
 /// | Custom counter logic  | ❌           | ✅                 |
-#[derive(Debug)]
 pub struct Rime<C: Counter, T: ?Sized> {
     _marker: PhantomData<(C, T)>,
     counter_ptr: *mut C,
@@ -145,28 +526,169 @@ impl<C: Counter, T: Sized> Rime<C, T> {
     /// - For dynamically sized values, use [`Rime::new`] instead
     pub fn steal(value: T) -> Self {
         unsafe {
-            let c_size = size_of::<C>();
-            let layout = Layout::from_size_align_unchecked(
-                size_of::<T>() + c_size, 
-                align_of::<C>().max(align_of::<T>()));
-
-            let raw = alloc(layout);
-            if raw.is_null() {
-                dealloc(raw, layout);
-                handle_alloc_error(layout);
-            }
+            let (counter_ptr, data_ptr) = allocate_block::<C>(Layout::new::<T>());
+            write(data_ptr as *mut T, value);
+
+            Self::from_raw(counter_ptr, data_ptr as *const T)
+        }
+    }
+
+    /// Replaces the payload in place and returns the previous value, if uniquely owned.
+    ///
+    /// Returns `None` (dropping `value`) without touching the allocation when other clones exist.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let mut rime = Rime::<u8, i32>::steal(1);
+    /// assert_eq!(rime.replace(2), Some(1));
+    /// assert_eq!(*rime, 2);
+    /// ```
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        if !self.is_unique() { return None }
+        Some(unsafe { std::mem::replace(&mut *self.as_mut_ptr(), value) })
+    }
+
+    /// Returns a deep copy of the payload in a new, unshared `Rime`.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, String>::steal("deep".to_string());
+    /// let copy = rime.clone_inner();
+    /// assert_eq!(*copy, *rime);
+    /// assert_ne!(copy, rime);
+    /// ```
+    #[inline]
+    pub fn clone_inner(&self) -> Self where T: Clone {
+        Self::steal((**self).clone())
+    }
 
-            let counter_ptr = raw as *mut C;
-            write(counter_ptr, C::new());
+    /// Returns an unshared deep copy of the payload: a fresh allocation with a count of one,
+    /// whatever the count of `self`.
+    ///
+    /// Alias of [`Rime::clone_inner`], named as the forced-detach counterpart of [`Rime::to_mut`].
+    #[inline(always)]
+    pub fn detached(&self) -> Self where T: Clone {
+        self.clone_inner()
+    }
 
-            let data_ptr = raw.add(c_size) as *mut T;
-            write(data_ptr, value);
+    /// Returns a mutable reference to the payload, cloning it into a fresh allocation first if
+    /// it is shared (clone-on-write).
+    ///
+    /// A uniquely owned allocation is reused as is. Otherwise `self` is repointed at a
+    /// [`detached`](Rime::detached) copy and drops its reference to the shared block, so the
+    /// other clones keep seeing the old value.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let mut rime = Rime::<u8, i32>::steal(1);
+    /// let shared = rime.clone();
+    ///
+    /// *rime.to_mut() += 1; // Shared, so the payload is copied first
+    /// assert_eq!((*rime, *shared), (2, 1));
+    /// ```
+    pub fn to_mut(&mut self) -> &mut T where T: Clone {
+        if !self.is_unique() {
+            *self = self.detached();
+        }
+        unsafe { &mut *self.inner_ptr.cast_mut() }
+    }
 
-            Self::from_raw(counter_ptr, data_ptr as *const T)
+    /// Moves the payload out and frees the block if this is the only reference.
+    ///
+    /// # Errors
+    /// Returns `self` unchanged when other clones (or weak references) share the allocation,
+    /// so the caller keeps its reference and can retry or fall back to [`Rime::clone_inner`].
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, String>::steal("owned".to_string());
+    /// let shared = rime.clone();
+    ///
+    /// let rime = rime.try_unwrap().unwrap_err(); // Still shared
+    /// drop(shared);
+    /// assert_eq!(rime.try_unwrap().unwrap(), "owned");
+    /// ```
+    pub fn try_unwrap(self) -> Result<T, Self> {
+        if !self.is_unique() { return Err(self) }
+
+        unsafe {
+            let value = read(self.inner_ptr);
+            let (counter_ptr, layout) = (self.counter_ptr, self.layout());
+            std::mem::forget(self);
+
+            drop_in_place(counter_ptr);
+            deallocate(counter_ptr.cast(), layout);
+            track_free();
+            Ok(value)
         }
     }
+
+    /// Alias of [`Rime::try_unwrap`], named after the `into_inner` convention.
+    ///
+    /// On contention the `Rime` is handed back in the `Err` variant rather than dropped.
+    #[inline(always)]
+    pub fn try_into_inner(self) -> Result<T, Self> {
+        self.try_unwrap()
+    }
+}
+
+/// Constructors for trait-object `Rime`s, naming the trait once in the type.
+///
+/// `RimeDyn` is never instantiated; its functions return plain [`Rime`]s. `RimeDyn::new` is
+/// [`Rime::new_unsize`] under a name that reads like `Box::new`, since [`Rime::new`] copies
+/// from a reference and cannot take an owned value.
+///
+/// # Example
+/// ```
+/// use std::{fmt::Display, sync::atomic::AtomicU8};
+/// use kroos::{Rime, RimeDyn};
+///
+/// let value: Rime<AtomicU8, dyn Display> = RimeDyn::<AtomicU8, dyn Display>::new(42);
+/// assert_eq!(value.to_string(), "42");
+/// ```
+pub struct RimeDyn<C: Counter, T: ?Sized>(PhantomData<fn() -> Rime<C, T>>, Infallible);
+
+impl<C: Counter, T: ?Sized> RimeDyn<C, T> {
+    /// Moves `value` into a new `Rime` holding it as `T`.
+    ///
+    /// As with [`Rime::new_unsize`], the payload destructor never runs.
+    #[inline(always)]
+    #[allow(clippy::new_ret_no_self)] // A constructor namespace, not a value type
+    pub fn new<U: std::marker::Unsize<T>>(value: U) -> Rime<C, T> {
+        Rime::new_unsize(value)
+    }
 }
 
+impl<T: ?Sized> Rime<Noop, T> {
+    /// Wraps borrowed `'static` data without allocating or copying.
+    ///
+    /// The [`Noop`] counter never reaches zero, so the data is never freed. Being a `const fn`,
+    /// this can initialize a `static`, letting literals be interned alongside allocated values.
+    ///
+    /// There is no `Flake` counterpart: a [`Flake`] owns its allocation and offers in-place
+    /// mutation (e.g. `fill`), which borrowed static data cannot support.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::{Noop, Rime};
+    ///
+    /// static NAME: Rime<Noop, str> = Rime::from_static("kroos");
+    /// assert_eq!(&*NAME, "kroos");
+    /// ```
+    #[inline(always)]
+    pub const fn from_static(data: &'static T) -> Self {
+        // `Noop` is zero-sized, so a dangling, well-aligned pointer is a valid counter.
+        Self { _marker: PhantomData, counter_ptr: dangling_mut(), inner_ptr: data }
+    }
+}
 
 impl<C: Counter, T: ?Sized> Rime<C, T> {
     /// Creates a `Rime` from raw pointers to the counter and data.
@@ -193,7 +715,7 @@ impl<C: Counter, T: ?Sized> Rime<C, T> {
     /// The caller must ensure that the memory layout corresponds to: `[ counter: C | data: T ]` and that both pointers are valid.
     ///
     /// For example:
-    /// ```
+    /// ```ignore
     /// use std::ptr::{metadata, from_raw_parts};
     /// use kroos::Rime;
     ///
@@ -206,6 +728,81 @@ impl<C: Counter, T: ?Sized> Rime<C, T> {
         Self::from_raw(counter_ptr, from_raw_parts::<T>(inner_ptr, metadata))
     }
 
+    /// Rebuilds a `Rime` from the block base alone, deriving the payload pointer from the
+    /// payload offset that `T`'s alignment (read from `metadata` for unsized payloads) implies.
+    ///
+    /// This is the single-pointer counterpart of [`Rime::from_raw`], for FFI layouts that only
+    /// pass the block base around. The offset is computed exactly as on allocation, so it
+    /// agrees with [`Rime::payload_offset`].
+    ///
+    /// # Safety
+    /// `counter_ptr` must come from [`Rime::counter_ptr`] of a `Rime<C, T>` whose reference is
+    /// being taken over (e.g. one passed to [`std::mem::forget`]), and `metadata` must be that
+    /// `Rime`'s pointer metadata.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u8]>::new(b"base");
+    /// let (base, len) = (rime.counter_ptr(), rime.len()); // The metadata of a slice is its length
+    /// std::mem::forget(rime);
+    ///
+    /// let rime = unsafe { Rime::<u8, [u8]>::from_counter_ptr(base, len) };
+    /// assert_eq!(&*rime, b"base");
+    /// ```
+    #[inline]
+    pub unsafe fn from_counter_ptr(counter_ptr: *mut C, metadata: <T as Pointee>::Metadata) -> Self {
+        let (_, offset) = block_layout::<C>(Layout::for_value_raw(from_raw_parts::<T>(counter_ptr as *const u8, metadata)));
+        Self::from_raw_parts(counter_ptr, counter_ptr.cast::<u8>().add(offset), metadata)
+    }
+
+    /// Adopts a block allocated with `layout`, freeing it with that exact layout.
+    ///
+    /// `Rime` recomputes its block layout on `Drop`, which only matches blocks laid out as
+    /// `[ C | padding | T ]`. The returned [`AdoptedRime`] stores `layout` instead and passes it
+    /// to the allocator verbatim, so the block may be over-aligned or padded, and the payload
+    /// may sit at any offset past the counter.
+    ///
+    /// The block is counted as a new allocation by `live_allocations` (with the
+    /// `track-allocations` feature), so only pass blocks allocated outside this crate. To take
+    /// back a block from a `Rime` passed to [`std::mem::forget`], use
+    /// [`Rime::from_counter_ptr`], which does not count it a second time.
+    ///
+    /// # Panics
+    /// Panics if the payload does not fit in `layout` after the counter, or if `layout` is less
+    /// aligned than the counter or the payload.
+    ///
+    /// # Safety
+    /// - `counter_ptr` must point to an initialized `C` at the start of a block allocated by the
+    ///   global allocator with `layout`.
+    /// - `inner_ptr` must point to a valid, aligned `T` inside that block.
+    ///
+    /// # Example
+    /// ```
+    /// use std::alloc::{Layout, alloc};
+    /// use kroos::Rime;
+    ///
+    /// let layout = Layout::from_size_align(64, 64).unwrap(); // Over-aligned and padded
+    /// let rime = unsafe {
+    ///     let block = alloc(layout);
+    ///     block.write(1u8);
+    ///     block.add(8).cast::<u64>().write(42);
+    ///     Rime::<u8, u64>::from_raw_with_layout(block, block.add(8).cast(), layout)
+    /// };
+    /// assert_eq!((*rime, rime.layout()), (42, layout));
+    /// ```
+    pub unsafe fn from_raw_with_layout(counter_ptr: *mut C, inner_ptr: *const T, layout: Layout) -> AdoptedRime<C, T> {
+        let size = std::mem::size_of_val_raw(inner_ptr);
+        let fits = (inner_ptr as *const u8 as usize).checked_sub(counter_ptr as usize)
+            .is_some_and(|offset| offset >= size_of::<C>() && offset.checked_add(size).is_some_and(|end| end <= layout.size()));
+        assert!(fits, "Rime::from_raw_with_layout: payload does not fit in the block after the counter");
+        assert!(layout.align() >= align_of::<C>().max(std::mem::align_of_val_raw(inner_ptr)), "Rime::from_raw_with_layout: layout is less aligned than the counter or payload");
+
+        track_alloc(); // Allocated outside the crate, so not yet counted; `Drop` untracks it
+        AdoptedRime::new(Self::from_raw(counter_ptr, inner_ptr), layout)
+    }
+
     /// Constructs a `Rime` by copying the contents of a reference into the allocation.
     ///
     /// The resulting pointer owns its own allocation and behaves like an `Arc` or `Rc`
@@ -224,29 +821,61 @@ impl<C: Counter, T: ?Sized> Rime<C, T> {
     /// ```
     pub fn new(value: &T) -> Self {
         unsafe {
-            let t_size = size_of_val(value);
-            let c_size = size_of::<C>();
-
-            let layout = Layout::from_size_align_unchecked(
-                c_size + t_size,
-                align_of::<C>().max(align_of_val(value))
-            );
-            
-            let raw = alloc(layout);
-            if raw.is_null() { 
-                dealloc(raw, layout);
-                handle_alloc_error(layout);
-            }
-            
-            let counter_ptr = raw as *mut C;
-            write(counter_ptr, C::new());
-
-            let inner_ptr = raw.add(c_size);
-            copy_nonoverlapping(value as *const T as *const u8, inner_ptr, t_size);
+            let (counter_ptr, inner_ptr) = allocate_block::<C>(Layout::for_value(value));
+            copy_nonoverlapping(value as *const T as *const u8, inner_ptr, size_of_val(value));
 
             Self::from_raw_parts(counter_ptr, inner_ptr, metadata(value))
         }
     }
+
+    /// Like [`Rime::new`], but refuses to allocate if the payload plus the counter would take
+    /// more than `max_bytes`.
+    ///
+    /// Meant for hardening parsers against untrusted input sizes. Padding between the counter
+    /// and the payload is not counted, so the block may be a few bytes larger than the bound.
+    ///
+    /// # Errors
+    /// Returns [`SizeLimitExceeded`] without allocating if `size_of_val(value) + size_of::<C>()`
+    /// is greater than `max_bytes`.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// assert!(Rime::<u8, [u8]>::with_size_limit(&[0; 15], 16).is_ok());
+    /// let error = Rime::<u8, [u8]>::with_size_limit(&[0; 16], 16).unwrap_err();
+    /// assert_eq!((error.size, error.limit), (17, 16));
+    /// ```
+    pub fn with_size_limit(value: &T, max_bytes: usize) -> Result<Self, SizeLimitExceeded> {
+        let size = size_of_val(value).saturating_add(size_of::<C>());
+        if size > max_bytes { return Err(SizeLimitExceeded { size, limit: max_bytes }) }
+        Ok(Self::new(value))
+    }
+
+    /// Moves a sized value into a `Rime` of an unsized type it coerces to, such as a closure
+    /// into `dyn Fn() -> i32` or an array into `[T]`.
+    ///
+    /// The value is written once into the block and the trait-object (or length) metadata comes
+    /// from the coercion, so no intermediate `Box` is needed. As with [`Rime::steal`], the
+    /// payload destructor never runs: closures should only capture values that need no drop.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let base = 40;
+    /// let add = Rime::<u8, dyn Fn(i32) -> i32>::new_unsize(move |x| base + x);
+    /// let shared = add.clone();
+    /// assert_eq!((add(1), shared(2)), (41, 42));
+    /// ```
+    pub fn new_unsize<U: std::marker::Unsize<T>>(value: U) -> Self {
+        unsafe {
+            let (counter_ptr, data_ptr) = allocate_block::<C>(Layout::new::<U>());
+            write(data_ptr as *mut U, value);
+
+            Self::from_raw(counter_ptr, data_ptr as *const U as *const T)
+        }
+    }
     
     /// Returns a raw fat pointer to the heap-allocated value.
     ///
@@ -260,180 +889,2549 @@ impl<C: Counter, T: ?Sized> Rime<C, T> {
         self.inner_ptr
     }
 
-    /// Returns a mutable raw fat pointer to the heap-allocated value.
+    /// Returns the number of `Rime` clones sharing this allocation.
     ///
-    /// Enables in-place mutation of the value stored by this `Rime`.
+    /// Atomic counters are read with `Relaxed` ordering: this is the cheap read meant for
+    /// diagnostics and logging, and in concurrent settings the value may be stale as soon as
+    /// it is returned (see [`StrongCount`]). Use [`Rime::strong_count_acquire`] when the count
+    /// gates further reads.
     ///
-    /// # Safety
-    /// - You must ensure there are no other aliases to the same memory (including other `Rime` clones).
-    /// - The memory must not be mutated in a way that violates the type’s layout or Rust’s aliasing rules.
-    /// - The `Rime` must remain alive for the duration of use, and must not be accessed concurrently from other threads.
+    /// The read never panics and never truncates: narrow counters are widened, so a `u8`
+    /// counter at its maximum reports `255` (a further clone panics instead of wrapping), and
+    /// counters wider than `usize` saturate at `usize::MAX`. Atomic counters stop at half their
+    /// range, so an `AtomicU8` tops out at `127`.
     #[inline(always)]
-    pub fn as_mut_ptr(&self) -> *mut T { 
-        self.inner_ptr.cast_mut()
+    pub fn strong_count(&self) -> StrongCount {
+        StrongCount(unsafe { (*self.counter_ptr).count_relaxed() })
     }
-}
 
-impl<C: Counter, T: ?Sized> Drop for Rime<C, T> {
+    /// Returns the number of `Rime` clones, reading atomic counters with `Acquire` ordering.
+    ///
+    /// The load synchronizes with the `Release` decrement of every clone dropped so far, so
+    /// once a thread observes a lower count, all writes made by the dropping threads before
+    /// their drop are visible to it.
     #[inline(always)]
-    fn drop(&mut self) {
+    pub fn strong_count_acquire(&self) -> usize {
+        unsafe { (*self.counter_ptr).count() }
+    }
+
+    /// Returns `n` clones, updating the counter once instead of `n` times.
+    ///
+    /// With atomic counters this is a single `fetch_add`, which is cheaper than `n` separate
+    /// read-modify-writes when fanning a value out to many consumers.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::AtomicUsize;
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<AtomicUsize, str>::new("fan-out");
+    /// let clones = rime.clone_n(3);
+    /// assert_eq!(rime.strong_count(), 4);
+    /// assert!(clones.iter().all(|clone| *clone == rime));
+    /// ```
+    pub fn clone_n(&self, n: usize) -> Vec<Self> {
+        let mut clones = Vec::with_capacity(n);
+        unsafe { (*self.counter_ptr).increment_by(n) }
+        clones.extend((0..n).map(|_| Self { _marker: PhantomData, counter_ptr: self.counter_ptr, inner_ptr: self.inner_ptr }));
+        clones
+    }
+
+    /// Returns `true` if this is the only `Rime` pointing to the allocation, and no
+    /// [`Weak`](crate::Weak) reference could upgrade to it.
+    #[inline(always)]
+    pub fn is_unique(&self) -> bool {
+        unsafe { (*self.counter_ptr).is_unique() }
+    }
+
+    /// Returns `true` if this `Rime` borrows `'static` data (see [`Rime::from_static`]) instead
+    /// of owning an allocation.
+    ///
+    /// Static instances have no block behind them, so [`Rime::layout`] and raw-handle APIs do
+    /// not describe a real allocation. Zero-sized blocks are never allocated either, so a
+    /// zero-sized counter paired with a zero-sized payload may also report `true`.
+    #[inline(always)]
+    pub fn is_static(&self) -> bool {
+        self.counter_ptr == dangling_mut()
+    }
+
+    /// Returns the [`Layout`] of the whole `[ C | padding | T ]` block.
+    ///
+    /// This is exactly the layout passed to the allocator when the last clone is dropped,
+    /// which makes it useful when integrating custom allocators or debugging alignment.
+    #[inline]
+    pub fn layout(&self) -> Layout {
+        block_layout::<C>(Layout::for_value(&**self)).0
+    }
+
+    /// Returns `true` if `other` has the same pointer metadata, and so the same block layout.
+    ///
+    /// For slices and strings this compares lengths; for trait objects it compares vtables, so
+    /// two types that happen to share a size are still incompatible (and, since the compiler may
+    /// duplicate a vtable, the same type can rarely compare incompatible too). Sized payloads are
+    /// always compatible. This is the precondition for reusing one block in place of another.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let a = Rime::<u8, [u8]>::new(b"abc");
+    /// assert!(a.layout_compatible(&Rime::new(b"xyz")));
+    /// assert!(!a.layout_compatible(&Rime::new(b"wxyz")));
+    /// ```
+    #[inline(always)]
+    pub fn layout_compatible(&self, other: &Self) -> bool {
+        metadata(self.inner_ptr) == metadata(other.inner_ptr)
+    }
+
+    /// Returns the byte offset of the payload from the start of the allocation.
+    ///
+    /// This is `size_of::<C>()` rounded up to the alignment of `T`, the same offset used when
+    /// the block is allocated, so `counter_ptr().cast::<u8>().add(payload_offset())` is the payload.
+    #[inline]
+    pub fn payload_offset(&self) -> usize {
+        block_layout::<C>(Layout::for_value(&**self)).1
+    }
+
+    /// Returns a raw pointer to the reference counter at the start of the allocation.
+    ///
+    /// Together with the documented `[ C | padding | T ]` layout, this lets FFI consumers and
+    /// external tools locate both the counter and the payload within the block.
+    ///
+    /// # Safety
+    /// Modifying the counter through this pointer bypasses `Rime`'s bookkeeping and can lead
+    /// to double frees or leaks. The pointer must not be dereferenced after all clones are dropped.
+    #[inline(always)]
+    pub fn counter_ptr(&self) -> *mut C {
+        self.counter_ptr
+    }
+
+    /// Returns a mutable raw fat pointer to the heap-allocated value.
+    ///
+    /// Enables in-place mutation of the value stored by this `Rime`.
+    ///
+    /// # Safety
+    /// - You must ensure there are no other aliases to the same memory (including other `Rime` clones).
+    /// - The memory must not be mutated in a way that violates the type’s layout or Rust’s aliasing rules.
+    /// - The `Rime` must remain alive for the duration of use, and must not be accessed concurrently from other threads.
+    ///
+    /// # Panics
+    /// In debug builds, panics if the allocation is shared (the strong count is not one), to
+    /// catch accidental mutation of shared data. The check compiles out in release builds; use
+    /// [`Rime::as_ptr`] for read-only raw access to shared payloads.
+    #[inline(always)]
+    pub fn as_mut_ptr(&self) -> *mut T {
+        debug_assert!(self.strong_count() == 1, "Rime::as_mut_ptr called on a shared allocation (strong count {})", self.strong_count());
+        self.inner_ptr.cast_mut()
+    }
+
+    /// Runs the payload destructor in place, keeping the block and the counter allocated.
+    ///
+    /// This lets object pools recycle a block: drop the old payload, [`Counter::reset`] the
+    /// counter, and write a new payload through [`Rime::as_mut_ptr`].
+    ///
+    /// # Safety
+    /// - This `Rime` must be the only handle to the allocation (no clones or weak references).
+    /// - The payload must not be read, and this `Rime` must not be cloned, until a new payload has
+    ///   been written in its place. Dropping the `Rime` in between is fine: it only frees the block.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::{Counter, Rime};
+    ///
+    /// let mut rime = Rime::<usize, String>::steal("old".to_string());
+    /// unsafe {
+    ///     rime.drop_payload_only();
+    ///     (*rime.counter_ptr()).reset();
+    ///     rime.as_mut_ptr().write("new".to_string());
+    /// }
+    /// assert_eq!(*rime, "new");
+    /// ```
+    #[inline]
+    pub unsafe fn drop_payload_only(&mut self) {
+        drop_in_place(self.as_mut_ptr())
+    }
+
+    /// Hashes the address of the shared allocation rather than its contents.
+    ///
+    /// This matches the pointer-identity [`PartialEq`] of `Rime`, so it is the right choice for
+    /// identity-keyed maps (e.g. interning tables). The [`Hash`] implementation, on the other hand,
+    /// forwards to the contents of `T` and suits value-keyed maps.
+    ///
+    /// # Example
+    /// ```
+    /// use std::hash::{DefaultHasher, Hasher};
+    /// use kroos::Rime;
+    ///
+    /// let a = Rime::<u8, str>::new("id");
+    /// let b = a.clone();
+    ///
+    /// let (mut ha, mut hb) = (DefaultHasher::new(), DefaultHasher::new());
+    /// a.hash_by_address(&mut ha);
+    /// b.hash_by_address(&mut hb);
+    /// assert_eq!(ha.finish(), hb.finish());
+    /// ```
+    #[inline(always)]
+    pub fn hash_by_address<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.inner_ptr.cast::<()>().hash(state)
+    }
+
+    /// Returns `true` if both `Rime`s point at the same object, ignoring pointer metadata.
+    ///
+    /// This is the identity behind `Rime == Rime`. For trait objects it compares the data
+    /// address only, so the same object reached through different vtables still counts as one
+    /// (vtables are not guaranteed to be unique, and one type may be viewed through several).
+    ///
+    /// # Example
+    /// ```
+    /// use std::fmt::Debug;
+    /// use kroos::Rime;
+    ///
+    /// let value = Rime::<u8, dyn Debug>::new_unsize(7u32);
+    /// assert!(value.same_object(&value.clone()));
+    /// assert!(!value.same_object(&Rime::new_unsize(7u32)));
+    /// ```
+    #[inline(always)]
+    pub fn same_object(&self, other: &Self) -> bool {
+        addr_eq(self.inner_ptr, other.inner_ptr)
+    }
+
+    /// Returns `true` if both `Rime`s have the same data address *and* the same metadata
+    /// (slice length or trait-object vtable).
+    ///
+    /// Clones of one `Rime` always compare equal. Beyond that, vtable comparison is unreliable:
+    /// the same type can get distinct vtables in different codegen units, so a `false` result
+    /// does not prove two objects differ. Prefer [`Rime::same_object`] for identity.
+    #[inline(always)]
+    #[allow(ambiguous_wide_pointer_comparisons)]
+    pub fn same_pointer(&self, other: &Self) -> bool {
+        self.inner_ptr == other.inner_ptr
+    }
+
+    /// Converts the `Rime` into an FFI-safe [`RimeRaw`] handle without touching the counter.
+    ///
+    /// The handle keeps the reference held by this `Rime` alive; use [`Rime::from_handle`]
+    /// to reclaim it, otherwise the allocation is leaked.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let handle = Rime::<u8, str>::new("opaque").into_handle();
+    /// let rime = unsafe { Rime::<u8, str>::from_handle(handle) };
+    /// assert_eq!(&*rime, "opaque");
+    /// ```
+    #[inline(always)]
+    pub fn into_handle(self) -> RimeRaw<C, T> {
+        let (data, metadata) = self.inner_ptr.to_raw_parts();
+        let counter = self.counter_ptr;
+        std::mem::forget(self);
+        RimeRaw { counter, data, metadata }
+    }
+
+    /// Reconstructs a `Rime` from a handle produced by [`Rime::into_handle`].
+    ///
+    /// # Safety
+    /// - The handle must originate from [`Rime::into_handle`] with the same `C` and `T`.
+    /// - The handle must not have been reclaimed before, and its fields must be unmodified.
+    #[inline(always)]
+    pub unsafe fn from_handle(handle: RimeRaw<C, T>) -> Self {
+        Self::from_raw(handle.counter, from_raw_parts(handle.data, handle.metadata))
+    }
+
+    /// Moves the payload into a [`Flake`] if this is the only reference, dropping the counter.
+    ///
+    /// The payload sits after the counter prefix, so it is copied into a new allocation of
+    /// exactly its own size and the old block is freed. Use this once a value stops being shared
+    /// to shed the counter overhead.
+    ///
+    /// # Errors
+    /// Returns `self` unchanged when other clones (or weak references) share the allocation.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, str>::new("alone");
+    /// let flake = rime.try_into_flake().unwrap();
+    /// assert_eq!(&*flake, "alone");
+    /// ```
+    pub fn try_into_flake(self) -> Result<Flake<T>, Self> {
+        if !self.is_unique() { return Err(self) }
+
+        let flake = Flake::new(&*self);
         unsafe {
-            if (*self.counter_ptr).decrement() {
-                let inner = &*self.inner_ptr;
-                dealloc(
-                    self.counter_ptr.cast(), 
-                    Layout::from_size_align_unchecked(
-                        size_of::<C>() + size_of_val(inner),
-                        align_of::<C>().max(align_of_val(inner))
-                    )
-                );
-            }
+            let (counter_ptr, layout) = (self.counter_ptr, self.layout());
+            std::mem::forget(self);
+
+            drop_in_place(counter_ptr);
+            deallocate(counter_ptr.cast(), layout);
+            track_free();
+        }
+        Ok(flake)
+    }
+
+    /// Moves the payload into a new block that uses the counter type `D`, if this is the only
+    /// reference.
+    ///
+    /// This is the sanctioned way to switch counting strategies, e.g. upgrading a
+    /// single-threaded `Rime<u8, T>` to a `Rime<AtomicUsize, T>` before sending it to another
+    /// thread. The payload is moved bitwise into a fresh `[ D | padding | T ]` block and the old
+    /// block is freed.
+    ///
+    /// # Errors
+    /// Returns `self` unchanged when other clones (or weak references) share the allocation.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::AtomicUsize;
+    /// use kroos::Rime;
+    ///
+    /// let local = Rime::<u8, str>::new("to share");
+    /// let shared: Rime<AtomicUsize, str> = local.map_counter().unwrap();
+    /// std::thread::spawn(move || assert_eq!(&*shared, "to share")).join().unwrap();
+    /// ```
+    pub fn map_counter<D: Counter>(self) -> Result<Rime<D, T>, Self> {
+        if !self.is_unique() { return Err(self) }
+
+        unsafe {
+            let (new_counter, data_ptr) = allocate_block::<D>(Layout::for_value(&*self));
+            copy_nonoverlapping(self.inner_ptr as *const u8, data_ptr, size_of_val(&*self));
+            let mapped = Rime::from_raw_parts(new_counter, data_ptr, metadata(self.inner_ptr));
+
+            let (counter_ptr, layout) = (self.counter_ptr, self.layout());
+            std::mem::forget(self);
+
+            drop_in_place(counter_ptr);
+            deallocate(counter_ptr.cast(), layout);
+            track_free();
+            Ok(mapped)
+        }
+    }
+
+    /// Borrows the payload as a [`Flake`], for APIs that take `&Flake<T>`.
+    ///
+    /// The view shares the payload without touching the reference count, and its lifetime is
+    /// tied to `&self`, so the `Rime` keeps the allocation alive for as long as the view exists.
+    /// Dropping the view never frees anything.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::{Flake, Rime};
+    ///
+    /// fn shout(flake: &Flake<str>) -> String {
+    ///     flake.to_uppercase()
+    /// }
+    ///
+    /// let rime = Rime::<u8, str>::new("quiet");
+    /// assert_eq!(shout(&rime.as_flake()), "QUIET");
+    /// ```
+    #[inline(always)]
+    pub fn as_flake(&self) -> FlakeView<'_, T> {
+        let (data, metadata) = self.inner_ptr.to_raw_parts();
+        let flake = unsafe { Flake::from_handle(FlakeRaw { data, metadata }) };
+        FlakeView { _marker: PhantomData, flake: std::mem::ManuallyDrop::new(flake) }
+    }
+
+    /// Returns a `Debug` view of the handle that shows the counter and payload pointers.
+    ///
+    /// `Rime` is only `Debug` when `T` is; use this for payloads without a `Debug` impl.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// struct Opaque;
+    /// let rime = Rime::<u8, Opaque>::steal(Opaque);
+    /// assert!(format!("{:?}", rime.debug_opaque()).starts_with("Rime { counter_ptr: 0x"));
+    /// ```
+    #[inline(always)]
+    pub fn debug_opaque(&self) -> DebugOpaque<'_, C, T> {
+        DebugOpaque(self)
+    }
+}
+
+/// A borrowed [`Flake`] view of a [`Rime`] payload, created by [`Rime::as_flake`].
+///
+/// It dereferences to `&Flake<T>` and never frees the payload, which stays owned by the `Rime`.
+pub struct FlakeView<'a, T: ?Sized> {
+    _marker: PhantomData<&'a T>,
+    flake: std::mem::ManuallyDrop<Flake<T>>,
+}
+
+impl<T: ?Sized> std::ops::Deref for FlakeView<'_, T> {
+    type Target = Flake<T>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Flake<T> {
+        &self.flake
+    }
+}
+
+/// A `Debug` view of a [`Rime`] that shows its pointers, created by [`Rime::debug_opaque`].
+pub struct DebugOpaque<'a, C: Counter, T: ?Sized>(&'a Rime<C, T>);
+
+impl<C: Counter, T: ?Sized> std::fmt::Debug for DebugOpaque<'_, C, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Rime")
+            .field("counter_ptr", &self.0.counter_ptr)
+            .field("inner_ptr", &self.0.inner_ptr.cast::<()>())
+            .finish()
+    }
+}
+
+/// An FFI-safe, opaque representation of a [`Rime`].
+///
+/// The handle bundles the counter pointer, the data pointer and the pointer metadata
+/// (length, vtable, etc.) in a `#[repr(C)]` layout, so foreign code can hold a shared
+/// kroos allocation and hand it back later.
+///
+/// # Safety
+/// - The C side must treat the handle as opaque and must **not** mutate its fields.
+/// - A handle owns one reference; it must be turned back into a `Rime` exactly once
+///   via [`Rime::from_handle`], or the allocation leaks.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RimeRaw<C: Counter, T: ?Sized> {
+    pub counter: *mut C,
+    pub data: *const (),
+    pub metadata: <T as Pointee>::Metadata,
+}
+
+impl<C: Counter> Rime<C, str> {
+    /// Returns the length of the string in bytes, read from the pointer metadata without touching
+    /// the payload.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let value = Rime::<u8, str>::new("héllo");
+    /// assert_eq!(value.len(), 6);
+    /// assert!(!value.is_empty());
+    /// ```
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        metadata(self.inner_ptr)
+    }
+
+    /// Returns `true` if the string has a length of zero.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a copy of the string in a new, unshared `Rime`.
+    #[inline]
+    pub fn clone_inner(&self) -> Self {
+        Self::new(self)
+    }
+
+    /// Returns a new `Rime` holding this string followed by `suffix`.
+    ///
+    /// A fresh block sized for the concatenation is allocated; the original allocation,
+    /// which may be shared, is left untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let base = Rime::<u8, str>::new("hello");
+    /// let longer = base.append_str(", world");
+    ///
+    /// assert_eq!(&*base, "hello");
+    /// assert_eq!(&*longer, "hello, world");
+    /// ```
+    pub fn append_str(&self, suffix: &str) -> Rime<C, str> {
+        let len = self.len().checked_add(suffix.len()).expect("Rime layout overflow");
+        unsafe {
+            let (counter_ptr, inner_ptr) = allocate_block::<C>(Layout::array::<u8>(len).expect("Rime layout overflow"));
+            copy_nonoverlapping(self.as_ptr() as *const u8, inner_ptr, self.len());
+            copy_nonoverlapping(suffix.as_ptr(), inner_ptr.add(self.len()), suffix.len());
+
+            Rime::from_raw_parts(counter_ptr, inner_ptr, len)
         }
     }
-}
 
-impl<C: Counter, T: ?Sized> Clone for Rime<C, T> {
-    #[inline]
-    fn clone(&self) -> Self {
-        unsafe { (*self.counter_ptr).increment() }
-        Self {
-            inner_ptr: self.inner_ptr,
-            counter_ptr: self.counter_ptr,
-            _marker: PhantomData
-        }
+    /// Returns a 64-bit FNV-1a hash of the UTF-8 bytes, stable across runs; see
+    /// [`Rime::content_fingerprint`] on byte slices, which gives the same value for the same bytes.
+    #[inline]
+    pub fn content_fingerprint(&self) -> u64 {
+        fingerprint(self.as_bytes())
+    }
+
+    /// Converts the string into a byte `Rime` sharing the same allocation and reference count.
+    ///
+    /// Every `str` is valid as bytes, so this cannot fail and nothing is copied; it is the
+    /// inverse of [`Rime::into_str`].
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let bytes = Rime::<u8, str>::new("bytes").into_bytes();
+    /// assert_eq!(&*bytes, b"bytes");
+    /// ```
+    #[inline]
+    pub fn into_bytes(self) -> Rime<C, [u8]> {
+        let (counter_ptr, inner_ptr) = (self.counter_ptr, self.inner_ptr as *const [u8]);
+        std::mem::forget(self);
+        Rime::from_raw(counter_ptr, inner_ptr)
+    }
+
+    /// Returns an iterator over the `char`s of the string.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, str>::new("añ");
+    /// assert!(rime.chars().eq(['a', 'ñ']));
+    /// ```
+    #[inline]
+    pub fn chars(&self) -> std::str::Chars<'_> {
+        (**self).chars()
+    }
+
+    /// Returns an iterator over the `char`s of the string and their byte offsets.
+    #[inline]
+    pub fn char_indices(&self) -> std::str::CharIndices<'_> {
+        (**self).char_indices()
+    }
+}
+
+impl<C: Counter, T> Rime<C, [T]> {
+    /// Returns the length of the slice in elements, read from the pointer metadata without touching
+    /// the payload.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let value = Rime::<u8, [u32]>::new(&[1, 2, 3]);
+    /// assert_eq!(value.len(), 3);
+    /// assert!(!value.is_empty());
+    /// ```
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        metadata(self.inner_ptr)
+    }
+
+    /// Returns `true` if the slice has a length of zero.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a deep copy of the slice in a new, unshared `Rime`.
+    ///
+    /// Elements are cloned one by one into the new block, dropping the already-cloned prefix
+    /// if a `clone` panics, even when they are `Copy`. The single-memory-copy fast path for
+    /// `Copy` elements is [`Rime::copy_inner`].
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u16]>::new(&[1, 2, 3]);
+    /// let copy = rime.clone_inner();
+    /// assert_eq!(&*copy, &*rime);
+    /// assert_eq!(copy.strong_count(), 1);
+    /// ```
+    #[inline]
+    pub fn clone_inner(&self) -> Self where T: Clone {
+        let mut builder = SliceBuilder::with_capacity(self.len());
+        self.iter().for_each(|item| builder.push(item.clone()));
+        builder.finish()
+    }
+
+    /// Returns a copy of the slice in a new, unshared `Rime`, made with a single memory copy.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u16]>::new(&[1, 2, 3]);
+    /// let copy = rime.copy_inner();
+    /// assert_eq!((&*copy, copy.strong_count().get()), (&[1, 2, 3][..], 1));
+    /// ```
+    #[inline]
+    pub fn copy_inner(&self) -> Self where T: Copy {
+        Rime::new(self)
+    }
+
+    /// Builds a slice `Rime` from an iterator of results, stopping at the first `Err`.
+    ///
+    /// Iterators with a reasonably tight upper size bound are written straight into a block of
+    /// that capacity, which is shrunk to the final length; others are buffered first. On error, every element produced so far is dropped and the block is
+    /// freed before the error is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let parsed = Rime::<u8, [i32]>::try_from_iter(["1", "2", "3"].map(str::parse::<i32>));
+    /// assert_eq!(&*parsed.unwrap(), &[1, 2, 3]);
+    ///
+    /// let failed = Rime::<u8, [i32]>::try_from_iter(["1", "x"].map(str::parse::<i32>));
+    /// assert!(failed.is_err());
+    /// ```
+    pub fn try_from_iter<E, I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Self, E> {
+        let mut iter = iter.into_iter();
+        let (lower, upper) = iter.size_hint();
+
+        // Reserve the upper bound only when the worst-case slack is modest; shrinking returns
+        // the excess, but a huge bound would still be allocated up front.
+        let builder = if let Some(upper) = upper.filter(|&upper| upper <= lower.saturating_mul(2).max(64)) {
+            let mut builder = SliceBuilder::with_capacity(upper);
+            while !builder.is_full() {
+                match iter.next() {
+                    Some(item) => builder.push(item?),
+                    None => break,
+                }
+            }
+            builder
+        } else {
+            let items = iter.collect::<Result<Vec<T>, E>>()?;
+            let mut builder = SliceBuilder::with_capacity(items.len());
+            items.into_iter().for_each(|item| builder.push(item));
+            builder
+        };
+
+        Ok(builder.finish())
+    }
+
+    /// Builds a slice `Rime` by mapping each item of `iter` through `f`, without collecting
+    /// into an intermediate `Vec` when the iterator reports a usable size hint.
+    ///
+    /// If `f` panics, the elements mapped so far are dropped and the block is freed.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let lengths = Rime::<u8, [usize]>::from_iter_map(["a", "bcd", "ef"], str::len);
+    /// assert_eq!(&*lengths, &[1, 3, 2]);
+    /// ```
+    pub fn from_iter_map<U, F: FnMut(U) -> T, I: IntoIterator<Item = U>>(iter: I, mut f: F) -> Self {
+        match Self::try_from_iter(iter.into_iter().map(|item| Ok::<T, Infallible>(f(item)))) {
+            Ok(rime) => rime,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Moves the elements of a `Vec` into a new slice `Rime` with a single memory copy.
+    ///
+    /// The elements are moved bitwise, so none is cloned or dropped; only the vector's (now
+    /// empty) buffer is freed. This is the cheapest way to share an owned buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let names = vec!["ada".to_string(), "grace".to_string()];
+    /// let shared = Rime::<u8, [String]>::from_vec(names);
+    /// assert_eq!(shared[1], "grace");
+    /// ```
+    pub fn from_vec(mut vec: Vec<T>) -> Self {
+        let len = vec.len();
+        unsafe {
+            let (counter_ptr, data_ptr) = allocate_block::<C>(Layout::array::<T>(len).expect("Rime layout overflow"));
+            copy_nonoverlapping(vec.as_ptr(), data_ptr as *mut T, len);
+            vec.set_len(0);
+
+            Self::from_raw_parts(counter_ptr, data_ptr, len)
+        }
+    }
+
+    /// Reverses the order of the elements in place.
+    ///
+    /// # Errors
+    /// Returns [`NotUnique`] without touching the payload if the allocation is shared.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let mut rime = Rime::<u8, [i32]>::new(&[1, 2, 3]);
+    /// rime.reverse().unwrap();
+    /// assert_eq!(&*rime, &[3, 2, 1]);
+    /// ```
+    pub fn reverse(&mut self) -> Result<(), NotUnique> {
+        if !self.is_unique() { return Err(NotUnique) }
+        unsafe { (*self.as_mut_ptr()).reverse() }
+        Ok(())
+    }
+
+    /// Sorts the elements in place.
+    ///
+    /// # Errors
+    /// Returns [`NotUnique`] without touching the payload if the allocation is shared.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let mut rime = Rime::<u8, [i32]>::new(&[3, 1, 2]);
+    /// rime.sort().unwrap();
+    /// assert_eq!(&*rime, &[1, 2, 3]);
+    /// ```
+    pub fn sort(&mut self) -> Result<(), NotUnique> where T: Ord {
+        if !self.is_unique() { return Err(NotUnique) }
+        unsafe { (*self.as_mut_ptr()).sort() }
+        Ok(())
+    }
+
+    /// Returns a mutable reference to the element at `index`.
+    ///
+    /// The bounds are checked first, so an out-of-range index is reported even on a shared
+    /// allocation.
+    ///
+    /// # Errors
+    /// Returns [`MutError::OutOfBounds`] if `index >= self.len()`, and [`MutError::NotUnique`] if
+    /// the allocation is shared.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::{MutError, Rime};
+    ///
+    /// let mut rime = Rime::<u8, [i32]>::new(&[1, 2, 3]);
+    /// *rime.try_get_mut(1).unwrap() = 20;
+    /// assert_eq!(&*rime, &[1, 20, 3]);
+    ///
+    /// assert_eq!(rime.try_get_mut(3), Err(MutError::OutOfBounds { index: 3, len: 3 }));
+    /// let _shared = rime.clone();
+    /// assert_eq!(rime.try_get_mut(0), Err(MutError::NotUnique));
+    /// ```
+    pub fn try_get_mut(&mut self, index: usize) -> Result<&mut T, MutError> {
+        let len = self.len();
+        if index >= len { return Err(MutError::OutOfBounds { index, len }) }
+        if !self.is_unique() { return Err(MutError::NotUnique) }
+        unsafe { Ok(&mut *self.as_mut_ptr().cast::<T>().add(index)) }
+    }
+
+    /// Returns the first element and the rest of the slice, or `None` if it is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u32]>::new(&[1, 2, 3]);
+    /// assert_eq!(rime.split_first(), Some((&1, &[2, 3][..])));
+    /// ```
+    #[inline]
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        (**self).split_first()
+    }
+
+    /// Returns the last element and the rest of the slice, or `None` if it is empty.
+    #[inline]
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        (**self).split_last()
+    }
+
+    /// Returns an iterator over `size` elements at a time; the last chunk may be shorter.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u8]>::new(&[1, 2, 3, 4, 5]);
+    /// let chunks: Vec<&[u8]> = rime.chunks(2).collect();
+    /// assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+    /// ```
+    #[inline]
+    pub fn chunks(&self, size: usize) -> std::slice::Chunks<'_, T> {
+        (**self).chunks(size)
+    }
+
+    /// Returns an iterator over exactly `size` elements at a time, skipping the remainder.
+    ///
+    /// The leftover elements are available through [`ChunksExact::remainder`](std::slice::ChunksExact::remainder).
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    #[inline]
+    pub fn chunks_exact(&self, size: usize) -> std::slice::ChunksExact<'_, T> {
+        (**self).chunks_exact(size)
+    }
+}
+
+impl<C: Counter, T, const N: usize> Rime<C, [T; N]> {
+    /// Moves a fixed-size array into a `Rime`.
+    ///
+    /// The length is part of the type, so the handle is a thin pointer pair (two words) instead
+    /// of the three words of a `Rime<C, [T]>`, and the block is laid out exactly like the slice
+    /// version. Use [`Rime::into_slice`] to hand it to code expecting a slice `Rime`.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u32; 4]>::from_array([1, 2, 3, 4]);
+    /// assert_eq!(rime[2], 3);
+    /// ```
+    #[inline]
+    pub fn from_array(array: [T; N]) -> Self {
+        Self::steal(array)
+    }
+
+    /// Converts into a slice `Rime` sharing the same allocation and reference count.
+    ///
+    /// Only the pointer gains its length metadata; nothing is copied.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let slice: Rime<u8, [u32]> = Rime::<u8, [u32; 3]>::from_array([1, 2, 3]).into_slice();
+    /// assert_eq!(&*slice, &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_slice(self) -> Rime<C, [T]> {
+        let (counter_ptr, inner_ptr) = (self.counter_ptr, self.inner_ptr as *const [T]);
+        std::mem::forget(self);
+        Rime::from_raw(counter_ptr, inner_ptr)
+    }
+}
+
+impl<C: Counter> Rime<C, [u8]> {
+    /// Converts the bytes into a `str` `Rime` sharing the same allocation, without copying.
+    ///
+    /// The bytes are validated as UTF-8 first; `str` and `[u8]` share a representation, so
+    /// only the pointer type changes. The reference count carries over unchanged.
+    ///
+    /// # Errors
+    /// Returns the original `Rime` along with the [`Utf8Error`](std::str::Utf8Error) if the
+    /// bytes are not valid UTF-8.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let text = Rime::<u8, [u8]>::new(b"bytes").into_str().unwrap();
+    /// assert_eq!(&*text, "bytes");
+    ///
+    /// let (bytes, _) = Rime::<u8, [u8]>::new(&[0xff]).into_str().unwrap_err();
+    /// assert_eq!(&*bytes, &[0xff]);
+    /// ```
+    pub fn into_str(self) -> Result<Rime<C, str>, (Self, std::str::Utf8Error)> {
+        if let Err(error) = std::str::from_utf8(&self) {
+            return Err((self, error));
+        }
+
+        let (counter_ptr, inner_ptr) = (self.counter_ptr, self.inner_ptr as *const str);
+        std::mem::forget(self);
+        Ok(Rime::from_raw(counter_ptr, inner_ptr))
+    }
+
+    /// Returns the index of the first occurrence of `needle`, or `None` if it does not occur.
+    ///
+    /// An empty needle matches at index `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u8]>::new(b"GET /index HTTP/1.1");
+    /// assert_eq!(rime.position(b"/index"), Some(4));
+    /// assert_eq!(rime.position(b"POST"), None);
+    /// ```
+    pub fn position(&self, needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() { return Some(0) }
+        self.windows(needle.len()).position(|window| window == needle)
+    }
+
+    /// Returns `true` if `needle` occurs anywhere in the payload.
+    #[inline]
+    pub fn contains_subslice(&self, needle: &[u8]) -> bool {
+        self.position(needle).is_some()
+    }
+
+    /// Returns `true` if the payload begins with `prefix`.
+    #[inline]
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        (**self).starts_with(prefix)
+    }
+
+    /// Returns `true` if the payload ends with `suffix`.
+    #[inline]
+    pub fn ends_with(&self, suffix: &[u8]) -> bool {
+        (**self).ends_with(suffix)
+    }
+
+    /// Feeds the bytes to `state` with a single [`Hasher::write`](std::hash::Hasher::write) call.
+    ///
+    /// Unlike the [`Hash`] implementation, which forwards to `[u8]` and prefixes the length, this
+    /// writes the raw bytes only. It suits checksums and content-addressed keys where the digest
+    /// must match hashing the same bytes elsewhere. Two payloads whose concatenations coincide
+    /// hash alike, so prefer `Hash` for map keys made of several fields.
+    ///
+    /// # Example
+    /// ```
+    /// use std::hash::{DefaultHasher, Hasher};
+    /// use kroos::Rime;
+    ///
+    /// let (mut a, mut b) = (DefaultHasher::new(), DefaultHasher::new());
+    /// Rime::<u8, [u8]>::new(b"digest").hash_bytes(&mut a);
+    /// b.write(b"digest");
+    /// assert_eq!(a.finish(), b.finish());
+    /// ```
+    #[inline]
+    pub fn hash_bytes<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write(self)
+    }
+
+    /// Returns a 64-bit FNV-1a hash of the bytes.
+    ///
+    /// Unlike [`Hash`], which depends on the hasher (and the random seed of `HashMap`'s
+    /// `RandomState`), the fingerprint is a fixed function of the contents: equal payloads
+    /// give equal fingerprints across allocations, processes and runs, which suits
+    /// content-addressed storage and deduplication. FNV is fast but not collision resistant,
+    /// so do not rely on it against adversarial input.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u8]>::new(b"a");
+    /// assert_eq!(rime.content_fingerprint(), 0xaf63_dc4c_8601_ec8c);
+    /// ```
+    #[inline]
+    pub fn content_fingerprint(&self) -> u64 {
+        fingerprint(self)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<C: Counter> Rime<C, [u8]> {
+    /// Copies the raw bytes of a slice of POD values into a new byte `Rime`.
+    ///
+    /// Unlike [`Rime::new`], the byte copy is backed by the [`bytemuck::Pod`] guarantee,
+    /// so no padding or invalid bit patterns can leak into the allocation.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u8]>::from_pod(&[1u16, 2]);
+    /// assert_eq!(rime.len(), 4);
+    /// ```
+    #[inline]
+    pub fn from_pod<P: bytemuck::Pod>(values: &[P]) -> Self {
+        Self::new(bytemuck::cast_slice(values))
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<C: Counter, T: bytemuck::Pod> Rime<C, [T]> {
+    /// Views the payload as its raw bytes, like [`bytemuck::bytes_of`] for slices.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(self)
+    }
+}
+
+#[cfg(feature = "zerocopy")]
+impl<C: Counter> Rime<C, [u8]> {
+    /// Reinterprets the payload bytes as a `U` without copying.
+    ///
+    /// Returns `None` if the payload length differs from `size_of::<U>()` or if the payload
+    /// is not suitably aligned for `U`.
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::Rime;
+    ///
+    /// let rime = Rime::<u8, [u8]>::new(&[7, 0, 0, 0]);
+    /// assert_eq!(rime.as_zerocopy::<[u8; 4]>(), Some(&[7, 0, 0, 0]));
+    /// assert_eq!(rime.as_zerocopy::<[u8; 2]>(), None);
+    /// ```
+    #[inline]
+    pub fn as_zerocopy<U>(&self) -> Option<&U>
+    where U: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable {
+        U::ref_from_bytes(self).ok()
+    }
+}
+
+/// Builds a [`Rime`] from a string or array literal without spelling out the payload type.
+///
+/// A string literal produces a `Rime<C, str>`, while a bracketed list produces a `Rime<C, [T]>`.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicU8;
+/// use kroos::rime;
+///
+/// let text = rime!(AtomicU8; "hello");
+/// let bytes = rime!(u8; [1u8, 2, 3]);
+///
+/// assert_eq!(&*text, "hello");
+/// assert_eq!(&*bytes, &[1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! rime {
+    ($counter:ty; $value:literal) => {
+        $crate::Rime::<$counter, str>::new($value)
+    };
+    ($counter:ty; [$($element:expr),* $(,)?]) => {
+        $crate::Rime::<$counter, [_]>::new(&[$($element),*])
+    };
+}
+
+impl<C: Counter> Rime<C, CStr> {
+    /// Constructs a `Rime` holding a copy of a nul-terminated C string.
+    ///
+    /// The terminator is copied along with the rest of the bytes and the length metadata
+    /// is preserved, so the shared string can be handed directly to C code.
+    ///
+    /// # Example
+    /// ```
+    /// use std::ffi::CStr;
+    /// use kroos::Rime;
+    ///
+    /// let r = Rime::<u8, CStr>::from_cstr(c"abc");
+    /// assert_eq!(r.to_bytes_with_nul(), b"abc\0");
+    /// ```
+    #[inline(always)]
+    pub fn from_cstr(value: &CStr) -> Self {
+        Self::new(value)
+    }
+
+    /// Returns a pointer to the first character of the string, terminated by a nul byte.
+    ///
+    /// # Safety
+    /// The pointer must not be dereferenced after all `Rime` clones are dropped.
+    #[inline(always)]
+    pub fn as_c_ptr(&self) -> *const c_char {
+        self.inner_ptr as *const c_char
+    }
+}
+
+impl<C: Counter, T: ?Sized> Drop for Rime<C, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unsafe {
+            if (*self.counter_ptr).decrement() && (*self.counter_ptr).release() {
+                // Read through the payload before `poison` overwrites it
+                let layout = self.layout();
+                poison(self.counter_ptr.cast(), layout);
+                deallocate(self.counter_ptr.cast(), layout);
+                track_free();
+            }
+        }
+    }
+}
+
+impl<C: Counter, T: ?Sized> Clone for Rime<C, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        unsafe { (*self.counter_ptr).increment() }
+        Self {
+            inner_ptr: self.inner_ptr,
+            counter_ptr: self.counter_ptr,
+            _marker: PhantomData
+        }
+    }
+}
+
+impl<C: Counter, T: ?Sized> From<&Rime<C, T>> for Rime<C, T> {
+    #[inline(always)]
+    fn from(value: &Rime<C, T>) -> Self {
+        value.clone()
+    }
+}
+
+impl<C: Counter> TryFrom<&[u8]> for Rime<C, str> {
+    type Error = std::str::Utf8Error;
+
+    /// Validates the bytes as UTF-8 and copies them into a new `str` allocation.
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::new(std::str::from_utf8(value)?))
+    }
+}
+
+impl<C: Counter, T: ?Sized> AsRef<T> for Rime<C, T> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        unsafe { &*self.inner_ptr }
+    }
+}
+
+impl<C: Counter, T: ?Sized> std::ops::Deref for Rime<C, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.inner_ptr }
+    }
+}
+
+impl<C: Counter, T: ?Sized> Eq for Rime<C, T> { }
+/// Compares by pointer identity: two `Rime`s are equal when they share one allocation.
+///
+/// Only the data address is compared (see [`Rime::same_object`]); slice lengths and trait-object
+/// vtables are ignored, since clones always carry the same metadata as the original.
+impl<C: Counter, T: ?Sized> PartialEq for Rime<C, T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        addr_eq(self.inner_ptr, other.inner_ptr)
+    }
+}
+
+/// Orders by contents, for payloads with a total order.
+///
+/// The `T: Ord` bound keeps partially ordered payloads such as `[f64]` away from `cmp`: they
+/// only get [`PartialOrd`], whose `partial_cmp` returns `None` for incomparable values (NaN)
+/// instead of panicking or picking an arbitrary order. Unlike equality, which is by identity,
+/// `cmp` may return `Equal` for two distinct allocations with equal contents.
+impl<C: Counter, T: ?Sized + Ord> Ord for Rime<C, T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        unsafe { (&*self.inner_ptr).cmp(&**other) }
+    }
+}
+
+/// Orders by contents through `T::partial_cmp`; the comparison path for partially ordered
+/// payloads, returning `None` when they are incomparable.
+impl<C: Counter, T: ?Sized + PartialOrd> PartialOrd for Rime<C, T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        unsafe { (&*self.inner_ptr).partial_cmp(&**other) }
+    }
+}
+
+/// Compares the contents of a `Rime` and a [`Flake`], unlike the pointer-identity `Rime == Rime`.
+impl<C: Counter, T: ?Sized + PartialEq> PartialEq<Flake<T>> for Rime<C, T> {
+    #[inline(always)]
+    fn eq(&self, other: &Flake<T>) -> bool {
+        **self == **other
+    }
+}
+
+/// Compares the contents of a [`Flake`] and a `Rime`, unlike the pointer-identity `Flake == Flake`.
+impl<C: Counter, T: ?Sized + PartialEq> PartialEq<Rime<C, T>> for Flake<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Rime<C, T>) -> bool {
+        **self == **other
+    }
+}
+
+/// Compares the string contents, so `rime == "literal"` works.
+impl<C: Counter> PartialEq<str> for Rime<C, str> {
+    #[inline(always)]
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl<C: Counter> PartialEq<&str> for Rime<C, str> {
+    #[inline(always)]
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+/// Compares the slice contents element by element.
+impl<C: Counter, T: PartialEq> PartialEq<[T]> for Rime<C, [T]> {
+    #[inline(always)]
+    fn eq(&self, other: &[T]) -> bool {
+        **self == *other
+    }
+}
+
+impl<C: Counter, T: PartialEq> PartialEq<&[T]> for Rime<C, [T]> {
+    #[inline(always)]
+    fn eq(&self, other: &&[T]) -> bool {
+        **self == **other
+    }
+}
+
+impl<C: Counter, T: ?Sized + PartialOrd> PartialOrd<Flake<T>> for Rime<C, T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Flake<T>) -> Option<std::cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<C: Counter, T: ?Sized + PartialOrd> PartialOrd<Rime<C, T>> for Flake<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Rime<C, T>) -> Option<std::cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+/// Hashes the contents of `T`, so equal values hash equally even across separate allocations.
+///
+/// Use [`Rime::hash_by_address`] to hash by pointer identity instead.
+impl<C: Counter, T: ?Sized + Hash> Hash for Rime<C, T> {
+    #[inline(always)]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        unsafe { (&*self.inner_ptr).hash(state) }
+    }
+}
+
+impl<C: Counter, T: ?Sized + std::fmt::Display> std::fmt::Display for Rime<C, T> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        unsafe { (&*self.inner_ptr).fmt(f) }
+    }
+}
+
+/// Shows the payload itself, like `Rc` and `Arc` do; see [`Rime::debug_opaque`] for payloads
+/// without a `Debug` impl.
+impl<C: Counter, T: ?Sized + std::fmt::Debug> std::fmt::Debug for Rime<C, T> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+/// Counters need no `Debug` impl: the `Debug` output of a `Rime` is that of its payload.
+impl<C: Counter> std::error::Error for Rime<C, dyn std::error::Error + Send + Sync> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        unsafe { (&*self.inner_ptr).source() }
+    }
+
+    #[inline]
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        unsafe { (&*self.inner_ptr).description() }
+    }
+}
+
+unsafe impl<C: Counter + Send, T: ?Sized + Send> Send for Rime<C, T> {}
+unsafe impl<C: Counter + Sync, T: ?Sized + Sync> Sync for Rime<C, T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::*;
+    use super::*;
+
+    #[test]
+    fn test_basic_clone_and_deref() {
+        let rime = Rime::<u8, str>::new("hello");
+        assert_eq!(&*rime, "hello");
+
+        let cloned = rime.clone();
+        assert_eq!(&*cloned, "hello");
+        assert_eq!(rime.as_ptr(), cloned.as_ptr()); // Same backing memory
+    }
+
+    #[test]
+    fn test_equality_and_ordering() {
+        let r1 = Rime::<u8, str>::new("abc");
+        let r2 = r1.clone();
+        let r3 = Rime::<u8, str>::new("abc");
+
+        assert_eq!(r1, r2);
+        assert_ne!(r1, r3); // Different allocations
+        assert!(r1 <= r2);
+        assert!(r3 >= r1);
+    }
+
+    #[test]
+    fn test_drop_deallocates() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        struct DropCounter(Rc<RefCell<u8>>);
+        impl Drop for DropCounter {
+            fn drop(&mut self) {
+                *self.0.borrow_mut() += 1;
+            }
+        }
+
+        let dropped = Rc::new(RefCell::new(0));
+        {
+            let counter = DropCounter(dropped.clone());
+            let r1 = Rime::<usize, _>::new(&counter);
+            let _r2 = r1.clone(); // two references
+        }
+
+        assert_eq!(*dropped.borrow(), 1); // Dropped once after refcount hits zero
+    }
+
+    #[test]
+    fn test_atomic_clone_thread_safe() {
+        use std::thread;
+
+        let rime = Rime::<AtomicUsize, str>::new("multi");
+        let mut handles = vec![];
+
+        for _ in 0..10 {
+            let cloned = rime.clone();
+            handles.push(thread::spawn(move || {
+                assert_eq!(&*cloned, "multi");
+            }));
+        }
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(&*rime, "multi");
+    }
+
+    #[test]
+    fn test_as_ref_and_conversion() {
+        let rime = Rime::<u8, str>::new("as_ref test");
+        let rime2: Rime<u8, str> = (&rime).into();
+
+        assert_eq!(rime.as_ref(), rime2.as_ref());
+    }
+
+    #[test]
+    fn test_cstr_ffi() {
+        use std::ffi::{CStr, c_char};
+
+        unsafe extern "C" {
+            fn strlen(s: *const c_char) -> usize;
+        }
+
+        let rime = Rime::<u8, CStr>::from_cstr(c"kroos");
+        let cloned = rime.clone();
+
+        assert_eq!(cloned.to_bytes_with_nul(), b"kroos\0");
+        assert_eq!(AsRef::<CStr>::as_ref(&rime), c"kroos");
+        assert_eq!(unsafe { strlen(cloned.as_c_ptr()) }, 5);
+    }
+
+    #[test]
+    fn test_handle_round_trip() {
+        let rime = Rime::<usize, [u16]>::new(&[1, 2, 3]);
+        let ptr = rime.as_ptr();
+        let cloned = rime.clone();
+
+        let handle = rime.into_handle();
+        assert_eq!(handle.metadata, 3);
+        assert_eq!(handle.data, ptr as *const ());
+
+        let restored = unsafe { Rime::<usize, [u16]>::from_handle(handle) };
+        assert_eq!(restored, cloned);
+        assert_eq!(&*restored, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_error_forwarding() {
+        use std::{error::Error, fmt};
+
+        #[derive(Debug)]
+        struct Inner;
+        impl fmt::Display for Inner {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("inner") }
+        }
+        impl Error for Inner {}
+
+        #[derive(Debug)]
+        struct Outer(Inner);
+        impl fmt::Display for Outer {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("outer") }
+        }
+        impl Error for Outer {
+            fn source(&self) -> Option<&(dyn Error + 'static)> { Some(&self.0) }
+        }
+
+        let outer = Outer(Inner);
+        let rime = Rime::<AtomicUsize, dyn Error + Send + Sync>::new(&outer);
+        let shared = rime.clone();
+
+        assert_eq!(shared.to_string(), "outer");
+        assert_eq!(shared.source().map(|e| e.to_string()).as_deref(), Some("inner"));
+
+        // A counter without a `Debug` impl still yields an `Error`
+        struct Bare(u32);
+        impl Counter for Bare {
+            fn new() -> Self { Bare(1) }
+            fn increment(&mut self) { self.0 += 1 }
+            fn decrement(&mut self) -> bool { self.0 -= 1; self.0 == 0 }
+            fn try_increment_nonzero(&mut self) -> bool { self.0 != 0 && { self.0 += 1; true } }
+            fn count(&self) -> usize { self.0 as usize }
+        }
+
+        let bare = Rime::<Bare, dyn Error + Send + Sync>::new(&Outer(Inner));
+        let error: &dyn Error = &bare;
+        assert_eq!((error.to_string(), format!("{error:?}")), ("outer".to_string(), "Outer(Inner)".to_string()));
+    }
+
+    #[test]
+    fn test_rime_macro() {
+        let text = crate::rime!(AtomicU8; "hello");
+        let bytes = crate::rime!(u8; [1u8, 2, 3]);
+        let empty: Rime<u8, [u16]> = crate::rime!(u8; []);
+
+        assert_eq!(&*text, "hello");
+        assert_eq!(&*bytes, &[1, 2, 3]);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_try_increment_nonzero() {
+        let mut plain = 1u8;
+        assert!(plain.try_increment_nonzero());
+        assert_eq!(plain, 2);
+        assert!(!plain.decrement());
+        assert!(plain.decrement());
+        assert!(!plain.try_increment_nonzero());
+        assert_eq!(plain, 0);
+
+        let mut cell = std::cell::Cell::new(0u32);
+        assert!(!cell.try_increment_nonzero());
+        assert_eq!(cell.get(), 0);
+    }
+
+    #[test]
+    fn test_try_increment_nonzero_race() {
+        use std::thread;
+
+        struct Shared(*mut AtomicUsize);
+        unsafe impl Send for Shared {}
+        unsafe impl Sync for Shared {}
+
+        for _ in 0..64 {
+            let counter = Box::into_raw(Box::new(<AtomicUsize as Counter>::new()));
+            let frees = AtomicUsize::new(0);
+            let shared = Shared(counter);
+
+            thread::scope(|scope| {
+                for _ in 0..4 {
+                    scope.spawn(|| {
+                        let counter = &shared;
+                        for _ in 0..1_000 {
+                            unsafe {
+                                if (*counter.0).try_increment_nonzero() && (*counter.0).decrement() {
+                                    frees.fetch_add(1, Ordering::Relaxed);
+                                }
+                            }
+                        }
+                    });
+                }
+
+                if unsafe { (*shared.0).decrement() } {
+                    frees.fetch_add(1, Ordering::Relaxed);
+                }
+            });
+
+            let counter = unsafe { Box::from_raw(counter) };
+            assert_eq!(counter.load(Ordering::Relaxed), 0);
+            assert_eq!(frees.load(Ordering::Relaxed), 1); // Never resurrected after reaching zero
+        }
+    }
+
+    #[test]
+    fn test_counter_ptr_offset() {
+        let rime = Rime::<u8, [u64]>::new(&[1, 2]);
+        let offset = size_of::<u8>().next_multiple_of(align_of::<u64>());
+        let payload = unsafe { rime.counter_ptr().cast::<u8>().add(offset) };
+
+        assert_eq!(payload as *const u64, rime.as_ptr() as *const u64);
+        assert_eq!(unsafe { *rime.counter_ptr() }, 1);
+        assert!(rime.as_ptr().cast::<u64>().is_aligned());
+
+        let stolen = Rime::<u16, u32>::steal(7);
+        let offset = size_of::<u16>().next_multiple_of(align_of::<u32>());
+        assert_eq!(unsafe { stolen.counter_ptr().cast::<u8>().add(offset) } as *const u32, stolen.as_ptr());
+    }
+
+    #[test]
+    fn test_payload_offset() {
+        fn check<C: Counter, T: ?Sized>(rime: &Rime<C, T>, expected: usize) {
+            assert_eq!(rime.payload_offset(), expected);
+            let payload = unsafe { rime.counter_ptr().cast::<u8>().add(expected) };
+            assert_eq!(payload as *const u8, rime.as_ptr() as *const u8);
+        }
+
+        check(&Rime::<u8, [u8]>::new(&[1, 2, 3]), 1);
+        check(&Rime::<u8, [u64]>::new(&[1]), 8);
+        check(&Rime::<AtomicU16, u32>::steal(7), 4);
+        check(&Rime::<AtomicUsize, str>::new("text"), size_of::<usize>());
+        check(&Rime::<u64, u16>::steal(7), 8);
+
+        #[repr(align(32))]
+        struct Wide(#[allow(dead_code)] u8);
+        check(&Rime::<AtomicUsize, Wide>::steal(Wide(1)), 32);
+    }
+
+    #[test]
+    fn test_from_counter_ptr_round_trip() {
+        let rime = Rime::<u8, [u8]>::new(b"round trip");
+        let clone = rime.clone();
+        let (base, len) = (rime.counter_ptr(), metadata(rime.as_ptr()));
+        std::mem::forget(rime);
+
+        let rebuilt = unsafe { Rime::<u8, [u8]>::from_counter_ptr(base, len) };
+        assert_eq!(&*rebuilt, b"round trip");
+        assert!(rebuilt.same_pointer(&clone));
+        assert_eq!(rebuilt.strong_count(), 2);
+
+        let dynamic = Rime::<AtomicU8, dyn std::fmt::Debug>::new_unsize(7u64); // Offset 8, read from the vtable
+        let (base, vtable) = (dynamic.counter_ptr(), metadata(dynamic.as_ptr()));
+        std::mem::forget(dynamic);
+        let rebuilt = unsafe { Rime::<AtomicU8, dyn std::fmt::Debug>::from_counter_ptr(base, vtable) };
+        assert_eq!(format!("{rebuilt:?}"), "7");
+        assert_eq!(rebuilt.payload_offset(), 8);
+    }
+
+    #[test]
+    fn test_counter_batch() {
+        use std::cell::Cell;
+        use crate::{Checked, WithWeak};
+
+        fn check<C: Counter>() {
+            let mut counter = C::new();
+            counter.increment_by(9);
+            assert_eq!(counter.count(), 10);
+            assert!(!counter.decrement_by(0));
+            assert!(!counter.decrement_by(4));
+            assert_eq!(counter.count(), 6);
+            assert!(counter.decrement_by(6));
+            assert_eq!(counter.count(), 0);
+        }
+
+        check::<u8>();
+        check::<usize>();
+        check::<Cell<u16>>();
+        check::<Cell<u64>>();
+        check::<AtomicU8>();
+        check::<AtomicUsize>();
+        check::<Checked<AtomicU32, 16>>();
+        check::<WithWeak<u32>>();
+    }
+
+    #[test]
+    #[should_panic(expected = "RefCount overflow")]
+    fn test_counter_batch_overflow() {
+        std::cell::Cell::<u8>::new(1).increment_by(255);
+    }
+
+    #[test]
+    #[should_panic(expected = "RefCount underflow")]
+    fn test_counter_batch_underflow() {
+        AtomicU16::new(2).decrement_by(3);
+    }
+
+    #[test]
+    fn test_counter_batch_underflow_leaves_count() {
+        let mut counter = AtomicU16::new(2);
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| counter.decrement_by(3))).is_err());
+        assert_eq!(counter.count(), 2);
+        assert!(counter.decrement_by(2));
+    }
+
+    #[test]
+    fn test_as_flake() {
+        fn total(flake: &Flake<[u32]>) -> u32 {
+            flake.iter().sum()
+        }
+
+        let rime = Rime::<AtomicUsize, [u32]>::new(&[1, 2, 3]);
+        {
+            let view = rime.as_flake();
+            assert_eq!(total(&view), 6);
+            assert_eq!(view.as_ptr(), rime.as_ptr()); // Same payload, no copy
+            assert_eq!(view.split_first(), Some((&1, &[2, 3][..])));
+        }
+
+        assert_eq!(rime.strong_count(), 1);
+        assert_eq!(&*rime, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_from_raw_with_layout() {
+        let (natural, _) = block_layout::<AtomicUsize>(Layout::array::<u16>(3).unwrap());
+        let layout = Layout::from_size_align(256, 128).unwrap();
+
+        let rime = unsafe {
+            let raw = std::alloc::alloc(layout);
+            raw.cast::<AtomicUsize>().write(AtomicUsize::new(1));
+            let data = raw.add(64).cast::<u16>(); // Further out than the natural payload offset
+            data.copy_from_nonoverlapping([4, 5, 6].as_ptr(), 3);
+            Rime::<AtomicUsize, [u16]>::from_raw_with_layout(raw.cast(), slice_from_raw_parts(data, 3), layout)
+        };
+        let clone = rime.clone();
+
+        assert_eq!(&*clone, &[4, 5, 6]);
+        assert_eq!((rime.layout(), clone.strong_count().get()), (layout, 2));
+        assert_ne!(natural, layout); // A plain `Rime` would free it with the wrong layout
+    }
+
+    #[test]
+    #[should_panic(expected = "payload does not fit")]
+    fn test_from_raw_with_layout_payload_before_counter() {
+        let value = 7u32;
+        let counter = (&raw const value).wrapping_add(1).cast::<u32>().cast_mut();
+        let _ = unsafe { Rime::<u32, u32>::from_raw_with_layout(counter, &value, Layout::new::<[u32; 2]>()) };
+    }
+
+    #[test]
+    fn test_debug_output() {
+        let text = Rime::<u8, str>::new("hello");
+        assert_eq!(format!("{text:?}"), "\"hello\"");
+        assert_eq!(format!("{:?}", Rime::<AtomicUsize, [u8]>::new(&[1, 2])), "[1, 2]");
+
+        struct Opaque;
+        let opaque = format!("{:?}", Rime::<u8, Opaque>::steal(Opaque).debug_opaque());
+        assert!(opaque.starts_with("Rime { counter_ptr: 0x"), "{opaque}");
+    }
+
+    #[test]
+    fn test_construction_panic_safety() {
+        use std::panic::catch_unwind;
+
+        struct Faulty;
+        impl Counter for Faulty {
+            fn new() -> Self { panic!("counter construction failed") }
+            fn increment(&mut self) {}
+            fn decrement(&mut self) -> bool { true }
+            fn try_increment_nonzero(&mut self) -> bool { true }
+            fn count(&self) -> usize { 1 }
+        }
+
+        // Every constructor fails before a block exists, so there is nothing to leak or double-free
+        assert!(catch_unwind(|| Rime::<Faulty, u64>::steal(1)).is_err());
+        assert!(catch_unwind(|| Rime::<Faulty, str>::new("new")).is_err());
+        assert!(catch_unwind(|| Rime::<Faulty, [u8]>::from_iter_map(0..4, |i| i)).is_err());
+        assert!(catch_unwind(|| crate::RimePool::<Faulty, u64>::new().steal(1)).is_err());
+    }
+
+    #[test]
+    fn test_try_into_flake() {
+        let rime = Rime::<AtomicUsize, [u64]>::new(&[1, 2, 3]);
+        let shared = rime.clone();
+
+        let Err(rime) = rime.try_into_flake() else { panic!("converted a shared Rime") };
+        assert_eq!(rime.strong_count(), 2);
+        drop(shared);
+
+        let flake = rime.try_into_flake().unwrap();
+        assert_eq!(&*flake, &[1, 2, 3]);
+        assert_eq!(flake.layout(), Layout::array::<u64>(3).unwrap()); // No counter prefix
+    }
+
+    #[test]
+    fn test_clone_n() {
+        let rime = Rime::<AtomicU32, [u8]>::new(b"shared");
+        let clones = rime.clone_n(16);
+
+        assert_eq!(rime.strong_count(), 17);
+        assert!(clones.iter().all(|clone| clone == &rime && &**clone == b"shared"));
+        drop(clones);
+        assert_eq!(rime.strong_count(), 1);
+
+        let plain = Rime::<u8, str>::new("plain");
+        assert!(plain.clone_n(0).is_empty());
+        assert_eq!(plain.clone_n(2).len(), 2);
+        assert_eq!(plain.strong_count(), 1);
+    }
+
+    #[test]
+    fn test_hash_by_address() {
+        use std::hash::{DefaultHasher, Hasher};
+
+        fn address_hash(rime: &Rime<u8, str>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            rime.hash_by_address(&mut hasher);
+            hasher.finish()
+        }
+
+        fn content_hash(rime: &Rime<u8, str>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            rime.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = Rime::<u8, str>::new("same");
+        let b = a.clone();
+        let c = Rime::<u8, str>::new("same");
+
+        assert_eq!(address_hash(&a), address_hash(&b));
+        assert_ne!(address_hash(&a), address_hash(&c));
+        assert_eq!(content_hash(&a), content_hash(&c));
+    }
+
+    #[test]
+    fn test_sort_and_reverse_unique() {
+        let mut rime = Rime::<u8, [i32]>::new(&[4, -1, 3, 0]);
+        assert_eq!(rime.sort(), Ok(()));
+        assert_eq!(&*rime, &[-1, 0, 3, 4]);
+
+        assert_eq!(rime.reverse(), Ok(()));
+        assert_eq!(&*rime, &[4, 3, 0, -1]);
+    }
+
+    #[test]
+    fn test_sort_and_reverse_shared() {
+        let mut rime = Rime::<AtomicU8, [i32]>::new(&[2, 1]);
+        let cloned = rime.clone();
+        assert_eq!(rime.strong_count(), 2);
+
+        assert_eq!(rime.sort(), Err(NotUnique));
+        assert_eq!(rime.reverse(), Err(NotUnique));
+        assert_eq!(&*cloned, &[2, 1]);
+
+        drop(cloned);
+        assert_eq!(rime.sort(), Ok(()));
+        assert_eq!(&*rime, &[1, 2]);
+    }
+
+    #[test]
+    fn test_try_get_mut() {
+        let mut rime = Rime::<AtomicU8, [i32]>::new(&[1, 2, 3]);
+        *rime.try_get_mut(2).unwrap() += 10;
+        assert_eq!(&*rime, &[1, 2, 13]);
+
+        assert_eq!(rime.try_get_mut(3), Err(MutError::OutOfBounds { index: 3, len: 3 }));
+        assert_eq!(
+            MutError::OutOfBounds { index: 3, len: 3 }.to_string(),
+            "index 3 is out of bounds for a slice of length 3",
+        );
+
+        let cloned = rime.clone();
+        assert_eq!(rime.try_get_mut(0), Err(MutError::NotUnique));
+        assert_eq!(rime.try_get_mut(5), Err(MutError::OutOfBounds { index: 5, len: 3 }));
+        assert_eq!(&*cloned, &[1, 2, 13]);
+
+        drop(cloned);
+        assert!(rime.try_get_mut(0).is_ok());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_from_pod() {
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Pixel { r: u8, g: u8, b: u8, a: u8 }
+
+        unsafe impl bytemuck::Zeroable for Pixel {}
+        unsafe impl bytemuck::Pod for Pixel {}
+
+        let pixels = [Pixel { r: 1, g: 2, b: 3, a: 4 }, Pixel { r: 5, g: 6, b: 7, a: 8 }];
+        let rime = Rime::<u8, [u8]>::from_pod(&pixels);
+
+        assert_eq!(&*rime, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(rime.as_bytes(), &*rime);
+
+        let wide = Rime::<u8, [u32]>::new(&[0x01020304]);
+        assert_eq!(wide.as_bytes(), &0x01020304u32.to_ne_bytes());
+        assert_eq!(bytemuck::cast_slice::<u8, Pixel>(rime.as_bytes())[1], pixels[1]);
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn test_as_zerocopy() {
+        use zerocopy::{FromBytes, Immutable, KnownLayout};
+
+        #[derive(FromBytes, KnownLayout, Immutable, Debug, PartialEq)]
+        #[repr(C)]
+        struct Header { tag: [u8; 2], len: [u8; 2] }
+
+        let rime = Rime::<u8, [u8]>::new(b"KR\x00\x05");
+        assert_eq!(rime.as_zerocopy::<Header>(), Some(&Header { tag: *b"KR", len: [0, 5] }));
+
+        let short = Rime::<u8, [u8]>::new(b"KR");
+        assert_eq!(short.as_zerocopy::<Header>(), None);
+
+        let aligned = Rime::<u64, [u8]>::new(&7u32.to_ne_bytes());
+        assert_eq!(aligned.as_zerocopy::<u32>(), Some(&7));
+    }
+
+    #[test]
+    fn test_count_relaxed() {
+        use std::cell::Cell;
+
+        let mut plain = <u16 as Counter>::new();
+        let mut cell = <Cell<u64> as Counter>::new();
+        let mut atomic = <AtomicU32 as Counter>::new();
+
+        for _ in 0..3 {
+            plain.increment();
+            cell.increment();
+            atomic.increment();
+        }
+
+        assert_eq!(plain.count_relaxed(), plain.count());
+        assert_eq!(cell.count_relaxed(), cell.count());
+        assert_eq!(atomic.count_relaxed(), atomic.count());
+        assert_eq!(plain.count_relaxed(), 4);
+    }
+
+    #[test]
+    fn test_with_size_limit() {
+        let payload = [7u32; 4];
+        let rime = Rime::<AtomicU32, [u32]>::with_size_limit(&payload, 20).unwrap();
+        assert_eq!(&*rime, &payload);
+
+        let error = Rime::<AtomicU32, [u32]>::with_size_limit(&payload, 19).unwrap_err();
+        assert_eq!(error, SizeLimitExceeded { size: 20, limit: 19 });
+        assert_eq!(error.to_string(), "allocation of 20 bytes exceeds the limit of 19 bytes");
+
+        let text = Rime::<u8, str>::with_size_limit("", 1).unwrap();
+        assert!(text.is_empty());
+    }
+
+    #[test]
+    fn test_raw_value_transitions() {
+        let rime = Rime::<AtomicU16, str>::new("generations");
+        let raw = || unsafe { (*rime.counter_ptr()).raw_value() };
+        assert_eq!(raw(), 1);
+
+        let first = rime.clone();
+        let second = first.clone();
+        assert_eq!(raw(), 3);
+
+        drop(first);
+        assert_eq!(raw(), 2);
+        let third = second.clone();
+        drop(second);
+        drop(third);
+        assert_eq!(raw(), 1);
+
+        let plain = Rime::<u8, [u8]>::new(&[1]);
+        let clones = [plain.clone(), plain.clone()];
+        assert_eq!(unsafe { (*plain.counter_ptr()).raw_value() }, 3);
+        drop(clones);
+        assert_eq!(unsafe { (*plain.counter_ptr()).raw_value() }, 1);
+    }
+
+    #[test]
+    fn test_append_str() {
+        let base = Rime::<u8, str>::new("kro");
+        let shared = base.clone();
+        let longer = shared.append_str("ös");
+
+        assert_eq!(&*longer, "kroös");
+        assert_eq!(&*base, "kro");
+        assert_eq!(base.strong_count(), 2);
+        assert_eq!(longer.strong_count(), 1);
+        assert_eq!(&*base.append_str(""), "kro");
+    }
+
+    #[test]
+    fn test_content_fingerprint() {
+        let a = Rime::<AtomicUsize, [u8]>::new(b"content");
+        let b = Rime::<u8, [u8]>::new(b"content");
+        assert!(!a.same_object(&a.clone_inner()));
+        assert_eq!(a.content_fingerprint(), a.clone_inner().content_fingerprint());
+        assert_eq!(a.content_fingerprint(), b.content_fingerprint());
+        assert_eq!(Rime::<u8, str>::new("content").content_fingerprint(), b.content_fingerprint());
+        assert_ne!(Rime::<u8, [u8]>::new(b"Content").content_fingerprint(), b.content_fingerprint());
+
+        // Reference values of 64-bit FNV-1a
+        assert_eq!(Rime::<u8, [u8]>::new(b"").content_fingerprint(), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(Rime::<u8, str>::new("foobar").content_fingerprint(), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_chars() {
+        let rime = Rime::<u8, str>::new("zß水🦀");
+        assert_eq!(rime.chars().collect::<Vec<_>>(), ['z', 'ß', '水', '🦀']);
+        assert_eq!(rime.chars().next_back(), Some('🦀'));
+        assert_eq!(rime.char_indices().map(|(i, _)| i).collect::<Vec<_>>(), [0, 1, 3, 6]);
+        assert_eq!(rime.len(), 10);
+    }
+
+    #[test]
+    fn test_try_from_bytes() {
+        let valid = Rime::<u8, str>::try_from("héllo".as_bytes()).unwrap();
+        assert_eq!(&*valid, "héllo");
+
+        let invalid = Rime::<u8, str>::try_from(&[b'a', 0xFF, b'b'][..]);
+        assert_eq!(invalid.unwrap_err().valid_up_to(), 1);
+    }
+
+    #[test]
+    fn test_zero_sized_payloads() {
+        let empty_str = Rime::<u8, str>::new("");
+        let empty_slice = Rime::<AtomicUsize, [u64]>::new(&[]);
+        let unit = Rime::<u32, ()>::steal(());
+        let cloned = empty_slice.clone();
+
+        assert_eq!(&*empty_str, "");
+        assert!(cloned.is_empty());
+        assert!(empty_slice.as_ptr().cast::<u64>().is_aligned());
+        assert_eq!(*unit, ());
+    }
+
+    #[test]
+    fn test_layout_compatible() {
+        let a = Rime::<AtomicU8, [u32]>::new(&[1, 2, 3]);
+        let b = Rime::<AtomicU8, [u32]>::new(&[4, 5, 6]);
+        let shorter = Rime::<AtomicU8, [u32]>::new(&[1, 2]);
+
+        assert!(a.layout_compatible(&b));
+        assert!(a.layout_compatible(&a.clone()));
+        assert!(!a.layout_compatible(&shorter));
+        assert_eq!(a.layout(), b.layout());
+
+        let number = Rime::<u8, dyn std::fmt::Debug>::new_unsize(1u32);
+        let same_type = Rime::<u8, dyn std::fmt::Debug>::new_unsize(2u32);
+        let other_type = Rime::<u8, dyn std::fmt::Debug>::new_unsize(3i32); // Same size, other vtable
+        assert!(number.layout_compatible(&same_type));
+        assert!(!number.layout_compatible(&other_type));
+    }
+
+    #[test]
+    fn test_layout_high_alignment() {
+        #[repr(align(64))]
+        struct Aligned([u8; 3]);
+
+        let rime = Rime::<u8, Aligned>::steal(Aligned([1, 2, 3]));
+        let layout = rime.layout();
+
+        assert_eq!(layout.align(), 64);
+        assert_eq!(layout.size(), 128); // 1 byte counter, 63 bytes padding, 64 bytes payload
+        assert_eq!(rime.0, [1, 2, 3]);
+
+        let slice = Rime::<u32, [u8]>::new(&[1, 2, 3, 4, 5]);
+        assert_eq!((slice.layout().size(), slice.layout().align()), (12, 4));
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut rime = Rime::<u8, i32>::steal(10);
+        assert_eq!(rime.replace(20), Some(10));
+        assert_eq!(*rime, 20);
+
+        let cloned = rime.clone();
+        assert_eq!(rime.replace(30), None);
+        assert_eq!(*cloned, 20);
     }
-}
 
-impl<C: Counter, T: ?Sized> From<&Rime<C, T>> for Rime<C, T> {
-    #[inline(always)]
-    fn from(value: &Rime<C, T>) -> Self {
-        value.clone()
+    #[test]
+    fn test_detached_and_to_mut() {
+        let mut rime = Rime::<AtomicUsize, i32>::steal(1);
+        let detached = rime.detached();
+        assert!(!detached.same_object(&rime));
+        assert_eq!((*detached, detached.strong_count().get(), rime.strong_count().get()), (1, 1, 1));
+
+        let address = rime.as_ptr();
+        *rime.to_mut() = 2; // Unique, so the block is reused
+        assert_eq!(rime.as_ptr(), address);
+
+        let shared = rime.clone();
+        assert_eq!(rime.detached().strong_count(), 1);
+        *rime.to_mut() = 3; // Shared, so the payload moves to a new block
+        assert_ne!(rime.as_ptr(), address);
+        assert_eq!((*rime, *shared), (3, 2));
+        assert_eq!((rime.strong_count().get(), shared.strong_count().get()), (1, 1));
     }
-}
 
-impl<C: Counter, T: ?Sized> AsRef<T> for Rime<C, T> {
-    #[inline]
-    fn as_ref(&self) -> &T {
-        unsafe { &*self.inner_ptr }
+    #[test]
+    fn test_compare_with_flake() {
+        let rime = Rime::<u8, str>::new("kroos");
+        let same = Flake::new("kroos");
+        let other = Flake::new("zzz");
+
+        assert!(rime == same);
+        assert!(same == rime);
+        assert!(rime != other);
+        assert!(rime < other);
+        assert!(other > rime);
+        assert_eq!(same.partial_cmp(&rime), Some(std::cmp::Ordering::Equal));
     }
-}
 
-impl<C: Counter, T: ?Sized> std::ops::Deref for Rime<C, T> {
-    type Target = T;
+    #[test]
+    fn test_split_first_last() {
+        let rime = Rime::<u8, [u32]>::new(&[10, 20, 30]);
+        assert_eq!(rime.split_first(), Some((&10, &[20, 30][..])));
+        assert_eq!(rime.split_last(), Some((&30, &[10, 20][..])));
 
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        unsafe { &*self.inner_ptr }
+        let empty = Rime::<u8, [u32]>::new(&[]);
+        assert_eq!(empty.split_first(), None);
+        assert_eq!(empty.split_last(), None);
     }
-}
 
-impl<C: Counter, T: ?Sized> Eq for Rime<C, T> { }
-impl<C: Counter, T: ?Sized> PartialEq for Rime<C, T> {
-    #[inline(always)]
-    fn eq(&self, other: &Self) -> bool {
-        addr_eq(self.inner_ptr, other.inner_ptr)
+    #[test]
+    fn test_chunks() {
+        let rime = Rime::<u8, [u8]>::new(&(0..10).collect::<Vec<u8>>());
+        let chunks: Vec<&[u8]> = rime.chunks(4).collect();
+        assert_eq!(chunks, [&[0, 1, 2, 3][..], &[4, 5, 6, 7], &[8, 9]]);
+
+        let exact = rime.chunks_exact(4);
+        assert_eq!(exact.remainder(), &[8, 9]);
+        assert_eq!(exact.count(), 2);
     }
-}
 
-impl<C: Counter, T: ?Sized + Ord> Ord for Rime<C, T> {
-    #[inline(always)]
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        unsafe { (&*self.inner_ptr).cmp(&*other) }
+    #[test]
+    fn test_try_from_iter() {
+        let exact = Rime::<u8, [u32]>::try_from_iter((1..=4).map(Ok::<u32, ()>)).unwrap();
+        assert_eq!(&*exact, &[1, 2, 3, 4]);
+
+        let buffered = Rime::<u8, [u32]>::try_from_iter((1..=10).filter(|n| n % 3 == 0).map(Ok::<u32, ()>)).unwrap();
+        assert_eq!(&*buffered, &[3, 6, 9]);
+
+        let empty = Rime::<u8, [u32]>::try_from_iter(std::iter::empty::<Result<u32, ()>>()).unwrap();
+        assert!(empty.is_empty());
     }
-}
 
-impl<C: Counter, T: ?Sized + PartialOrd> PartialOrd for Rime<C, T> {
-    #[inline(always)]
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        unsafe { (&*self.inner_ptr).partial_cmp(&*other) }
+    #[test]
+    fn test_from_static() {
+        static DATA: [u16; 3] = [1, 2, 3];
+        let literal = "interned";
+
+        let text = Rime::<Noop, str>::from_static(literal);
+        let slice = Rime::<Noop, [u16]>::from_static(&DATA);
+        let cloned = text.clone();
+
+        assert_eq!(text.as_ptr(), literal as *const str); // Points at the data, no copy
+        assert_eq!(slice.as_ptr(), &DATA as *const [u16]);
+        drop((text, slice));
+        assert_eq!(&*cloned, "interned");
     }
-}
 
-impl<C: Counter, T: ?Sized + Hash> Hash for Rime<C, T> {
-    #[inline(always)]
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        unsafe { (&*self.inner_ptr).hash(state) }
+    #[test]
+    fn test_is_static() {
+        let borrowed = Rime::<Noop, str>::from_static("static");
+        let owned = Rime::<Noop, str>::new("owned");
+        let counted = Rime::<AtomicUsize, str>::new("counted");
+
+        assert!(borrowed.is_static());
+        assert!(borrowed.clone().is_static());
+        assert!(!owned.is_static());
+        assert!(!counted.is_static());
     }
-}
 
-unsafe impl<C: Counter + Send, T: ?Sized + Send> Send for Rime<C, T> {}
-unsafe impl<C: Counter + Sync, T: ?Sized + Sync> Sync for Rime<C, T> {}
+    #[test]
+    fn test_try_unwrap() {
+        let rime = Rime::<AtomicUsize, Vec<u32>>::steal(vec![1, 2, 3]);
+        let shared = rime.clone();
 
-#[cfg(test)]
-mod tests {
-    use std::sync::atomic::*;
-    use super::*;
+        let rime = rime.try_into_inner().unwrap_err();
+        assert_eq!(rime.strong_count(), 2); // The failed attempt kept its reference
+        drop(shared);
+
+        assert_eq!(rime.try_into_inner().unwrap(), [1, 2, 3]);
+        assert!(Rime::<Noop, u8>::from_static(&7).try_unwrap().is_err());
+    }
 
     #[test]
-    fn test_basic_clone_and_deref() {
-        let rime = Rime::<u8, str>::new("hello");
-        assert_eq!(&*rime, "hello");
+    fn test_from_iter_map() {
+        let words: &[&str] = &["map", "into", "lengths"];
+        let lengths = Rime::<u8, [usize]>::from_iter_map(words, |word| word.len());
+        assert_eq!(&*lengths, &[3, 4, 7]);
 
-        let cloned = rime.clone();
-        assert_eq!(&*cloned, "hello");
-        assert_eq!(rime.as_ptr(), cloned.as_ptr()); // Same backing memory
+        let unbounded = Rime::<u8, [u32]>::from_iter_map((1..).take_while(|&n| n < 4), |n: u32| n * n);
+        assert_eq!(&*unbounded, &[1, 4, 9]);
     }
 
     #[test]
-    fn test_equality_and_ordering() {
-        let r1 = Rime::<u8, str>::new("abc");
-        let r2 = r1.clone();
-        let r3 = Rime::<u8, str>::new("abc");
+    fn test_from_iter_map_panic_cleanup() {
+        use std::{panic, rc::Rc};
 
-        assert_eq!(r1, r2);
-        assert_ne!(r1, r3); // Different allocations
-        assert!(r1 <= r2);
-        assert!(r3 >= r1);
+        let tracker = Rc::new(());
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            Rime::<u8, [Rc<()>]>::from_iter_map(0..4, |i| {
+                assert!(i < 2, "mapping failed");
+                tracker.clone()
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&tracker), 1); // Mapped elements were dropped
     }
 
     #[test]
-    fn test_drop_deallocates() {
-        use std::cell::RefCell;
+    fn test_into_str() {
+        let bytes = Rime::<AtomicUsize, [u8]>::new("héllo".as_bytes());
+        let shared = bytes.clone();
+        let address = bytes.as_ptr() as *const u8;
+
+        let text = bytes.into_str().unwrap();
+        assert_eq!(&*text, "héllo");
+        assert_eq!(text.as_ptr() as *const u8, address); // Same allocation, no copy
+        assert_eq!(shared.strong_count(), 2);
+
+        let (invalid, error) = Rime::<u8, [u8]>::new(&[b'o', b'k', 0xc3]).into_str().unwrap_err();
+        assert_eq!(&*invalid, &[b'o', b'k', 0xc3]);
+        assert_eq!(error.valid_up_to(), 2);
+    }
+
+    #[test]
+    fn test_into_bytes() {
+        let text = Rime::<AtomicUsize, str>::new("héllo");
+        let shared = text.clone();
+        let (address, layout) = (text.as_ptr() as *const u8, text.layout());
+
+        let bytes = text.into_bytes();
+        assert_eq!(&*bytes, "héllo".as_bytes());
+        assert_eq!(bytes.as_ptr() as *const u8, address); // Same allocation, no copy
+        assert_eq!(bytes.layout(), layout);
+        assert_eq!(shared.strong_count(), 2);
+
+        drop(shared);
+        assert_eq!(&*bytes.into_str().unwrap(), "héllo");
+    }
+
+    #[test]
+    fn test_partial_cmp_floats() {
+        use std::cmp::Ordering;
+
+        let low = Rime::<u8, [f64]>::new(&[1.0, 2.0]);
+        let high = Rime::<u8, [f64]>::new(&[1.0, 3.0]);
+        let nan = Rime::<u8, [f64]>::new(&[1.0, f64::NAN]);
+
+        assert_eq!(low.partial_cmp(&high), Some(Ordering::Less));
+        assert_eq!(high.partial_cmp(&low), Some(Ordering::Greater));
+        assert_eq!(low.partial_cmp(&low.clone_inner()), Some(Ordering::Equal));
+        assert!(low < high);
+
+        assert_eq!(nan.partial_cmp(&low), None);
+        assert_eq!(nan.partial_cmp(&nan.clone()), None); // NaN is incomparable even with itself
+        assert_eq!([nan < low, nan > low, nan <= low, nan >= low], [false; 4]);
+
+        // A difference before the NaN still decides the order
+        let zero = Rime::<u8, [f64]>::new(&[0.0, f64::NAN]);
+        assert_eq!(zero.partial_cmp(&nan), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn test_compare_with_contents() {
+        let text = Rime::<u8, str>::new("hello");
+        assert!(text == "hello");
+        assert!(text != "world");
+        assert!(text == *"hello");
+
+        let expected: &[i32] = &[1, 2, 3];
+        let slice = Rime::<u8, [i32]>::new(expected);
+        assert!(slice == expected);
+        assert!(slice != [1, 2][..]);
+    }
+
+    #[test]
+    fn test_strong_count_acquire() {
+        let results = Rime::<AtomicUsize, [AtomicUsize]>::new(&[AtomicUsize::new(0), AtomicUsize::new(0)]);
+
+        std::thread::scope(|scope| {
+            for slot in 0..2 {
+                let worker = results.clone();
+                scope.spawn(move || {
+                    worker[slot].store(slot + 10, Ordering::Relaxed);
+                    drop(worker); // Release decrement publishes the store
+                });
+            }
+
+            while results.strong_count_acquire() > 1 {
+                std::hint::spin_loop();
+            }
+            assert_eq!(results[0].load(Ordering::Relaxed), 10);
+            assert_eq!(results[1].load(Ordering::Relaxed), 11);
+        });
+
+        assert_eq!(results.strong_count(), results.strong_count_acquire());
+    }
+
+    #[test]
+    fn test_byte_search() {
+        let rime = Rime::<u8, [u8]>::new(b"abcabcd");
+
+        assert_eq!(rime.position(b"cd"), Some(5));
+        assert_eq!(rime.position(b"abc"), Some(0));
+        assert_eq!(rime.position(b""), Some(0));
+        assert_eq!(rime.position(b"abcabcde"), None); // Longer than the payload
+        assert!(rime.contains_subslice(b"cab"));
+        assert!(!rime.contains_subslice(b"dab"));
+        assert!(rime.starts_with(b"abca") && !rime.starts_with(b"b"));
+        assert!(rime.ends_with(b"bcd") && !rime.ends_with(b"abc"));
+    }
+
+    #[test]
+    fn test_slice_builder_shrink() {
+        let exact = |len| block_layout::<u8>(Layout::array::<u64>(len).unwrap()).0;
+
+        let mut builder = SliceBuilder::<u8, u64>::with_capacity(16);
+        (0..5).for_each(|i| builder.push(i));
+        builder.shrink_to(8);
+        assert_eq!(builder.capacity, 8);
+        builder.shrink_to(0); // Never below the written length
+        assert_eq!(builder.capacity, 5);
+
+        let rime = builder.finish();
+        assert_eq!(&*rime, &[0, 1, 2, 3, 4]);
+        assert_eq!(rime.layout(), exact(5));
+
+        // A bounded iterator reserves its upper bound and shrinks on finish
+        let filtered = Rime::<u8, [u64]>::try_from_iter((0..40).filter(|n| n % 10 == 0).map(Ok::<u64, ()>)).unwrap();
+        assert_eq!(&*filtered, &[0, 10, 20, 30]);
+        assert_eq!(filtered.layout(), exact(4));
+    }
+
+    #[test]
+    fn test_try_from_iter_error_cleanup() {
         use std::rc::Rc;
 
-        struct DropCounter(Rc<RefCell<u8>>);
-        impl Drop for DropCounter {
+        let tracker = Rc::new(());
+        let items = (0..5).map(|i| if i < 3 { Ok(tracker.clone()) } else { Err(i) });
+
+        let exact = Rime::<u8, [Rc<()>]>::try_from_iter(items.clone());
+        assert_eq!(exact.unwrap_err(), 3);
+        assert_eq!(Rc::strong_count(&tracker), 1); // Initialized elements were dropped
+
+        let buffered = Rime::<u8, [Rc<()>]>::try_from_iter(items.filter(|_| true));
+        assert_eq!(buffered.unwrap_err(), 3);
+        assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+
+    #[test]
+    fn test_copy_inner_slice() {
+        let large: Vec<u8> = (0..1 << 20).map(|i| i as u8).collect();
+        let rime = Rime::<AtomicUsize, [u8]>::new(&large);
+        let shared = rime.clone();
+
+        let copy = rime.copy_inner();
+        assert_eq!(&*copy, &*large);
+        assert_ne!(copy, rime);
+        assert_eq!((copy.strong_count().get(), shared.strong_count().get()), (1, 2));
+        assert_eq!(&*rime.clone_inner(), &*large);
+    }
+
+    #[test]
+    fn test_clone_inner_clone_slice() {
+        let owned = [String::from("a"), String::from("bc")];
+        let rime = Rime::<u8, [String]>::try_from_iter(owned.iter().cloned().map(Ok::<_, ()>)).unwrap();
+        let copy = rime.clone_inner();
+
+        assert_eq!(&*copy, &owned);
+        assert_ne!(copy[0].as_ptr(), rime[0].as_ptr()); // Elements were cloned, not bit-copied
+
+        let text = Rime::<u8, str>::new("text").clone_inner();
+        assert_eq!(&*text, "text");
+        assert_eq!(*Rime::<u8, String>::steal("s".into()).clone_inner(), "s");
+    }
+
+    #[test]
+    fn test_len_and_is_empty() {
+        let empty = Rime::<u8, str>::new("");
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+
+        let text = Rime::<u8, str>::new("día");
+        assert_eq!(text.len(), 4);
+        assert!(!text.is_empty());
+
+        let bytes = Rime::<u8, [u8]>::new(b"");
+        assert!(bytes.is_empty());
+        let bytes = Rime::<u8, [u8]>::new(b"abc");
+        assert_eq!(bytes.len(), 3);
+        assert!(!bytes.is_empty());
+    }
+
+    #[test]
+    fn test_drop_payload_only_and_reuse() {
+        use std::{cell::Cell, rc::Rc};
+
+        struct Tracked(Rc<Cell<usize>>, u32);
+        impl Drop for Tracked {
+            fn drop(&mut self) { self.0.set(self.0.get() + 1) }
+        }
+
+        let drops = Rc::new(Cell::new(0));
+        let mut rime = Rime::<AtomicUsize, Tracked>::steal(Tracked(drops.clone(), 1));
+        let address = rime.as_ptr();
+
+        unsafe {
+            rime.drop_payload_only();
+            assert_eq!(drops.get(), 1);
+
+            (*rime.counter_ptr()).reset();
+            rime.as_mut_ptr().write(Tracked(drops.clone(), 2));
+        }
+
+        assert_eq!((rime.as_ptr(), rime.1, rime.strong_count().get()), (address, 2, 1));
+        let clone = rime.clone();
+        drop(rime);
+        assert_eq!(clone.strong_count(), 1);
+        assert_eq!(drops.get(), 1); // Rime never runs payload destructors
+
+        let mut clone = clone;
+        unsafe { clone.drop_payload_only() }
+        assert_eq!(drops.get(), 2);
+        drop(clone);
+
+        let mut counter = <Cell<u8> as Counter>::new();
+        counter.increment_by(4);
+        counter.reset();
+        assert_eq!(counter.count(), 1);
+    }
+
+    #[test]
+    fn test_map_counter() {
+        let local = Rime::<u8, str>::new("upgrade me");
+        let address = local.as_ptr();
+        let shared: Rime<AtomicUsize, str> = local.map_counter().unwrap();
+
+        assert_eq!(&*shared, "upgrade me");
+        assert_eq!(shared.strong_count(), 1);
+        assert_ne!(shared.as_ptr(), address);
+
+        let other = shared.clone();
+        let handle = std::thread::spawn(move || other.len());
+        assert_eq!(handle.join().unwrap(), 10);
+
+        let held = shared.clone();
+        let shared = shared.map_counter::<u8>().unwrap_err(); // Still shared
+        drop(held);
+        let back: Rime<u8, str> = shared.map_counter().unwrap();
+        assert_eq!(&*back, "upgrade me");
+
+        let moved = Rime::<u8, String>::steal("heap".to_string()).map_counter::<usize>().unwrap();
+        assert_eq!(*moved, "heap");
+        drop(moved.try_unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_hash_bytes() {
+        use std::hash::{DefaultHasher, Hasher};
+
+        let payload: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let rime = Rime::<u8, [u8]>::new(&payload);
+
+        let (mut by_rime, mut by_write) = (DefaultHasher::new(), DefaultHasher::new());
+        rime.hash_bytes(&mut by_rime);
+        by_write.write(&payload);
+        assert_eq!(by_rime.finish(), by_write.finish());
+
+        let (mut keyed, mut sliced) = (DefaultHasher::new(), DefaultHasher::new());
+        rime.hash(&mut keyed);
+        payload[..].hash(&mut sliced);
+        assert_eq!(keyed.finish(), sliced.finish()); // Hash still matches `[u8]`
+    }
+
+    #[test]
+    fn test_new_unsize_closure() {
+        let offset = Rime::<u8, i32>::steal(40);
+        let captured = offset.clone();
+        let call = Rime::<u8, dyn Fn() -> i32>::new_unsize(move || *captured + 2);
+        let clone = call.clone();
+
+        assert_eq!((call(), clone()), (42, 42));
+        assert_eq!(call.strong_count(), 2);
+        assert_eq!(offset.strong_count(), 2);
+
+        let mut counter = 0;
+        let tick = Rime::<u8, dyn FnMut() -> i32>::new_unsize(move || { counter += 1; counter });
+        let tick = unsafe { &mut *tick.as_mut_ptr() };
+        assert_eq!((tick(), tick()), (1, 2));
+
+        let array = Rime::<u8, [u16]>::new_unsize([1, 2, 3]);
+        assert_eq!(&*array, &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_rime_dyn_display() {
+        use std::fmt::Display;
+
+        let value = RimeDyn::<AtomicU8, dyn Display>::new(42);
+        let clone = value.clone();
+        assert_eq!((value.to_string(), clone.to_string()), ("42".to_string(), "42".to_string()));
+        assert_eq!(value.strong_count(), 2);
+        assert!(value.same_object(&clone));
+
+        let mixed: [Rime<AtomicU8, dyn Display>; 2] = [RimeDyn::new("text"), RimeDyn::new(1.5)];
+        assert_eq!(format!("{} {}", mixed[0], mixed[1]), "text 1.5");
+    }
+
+    #[test]
+    fn test_strong_count_snapshot() {
+        let rime = Rime::<AtomicUsize, [u8]>::new(b"snapshot");
+        let before = rime.strong_count();
+        assert!(before.is_one());
+
+        let clone = rime.clone();
+        let after = rime.strong_count();
+        assert!(before.is_one()); // A snapshot does not follow later clones
+        assert!(!after.is_one());
+        assert!(before < after && after > 1);
+        assert_eq!(usize::from(after), rime.strong_count_acquire());
+        assert_eq!(format!("{after} {after:?}"), "2 2");
+
+        drop(clone);
+        assert!(rime.strong_count() < after);
+    }
+
+    #[test]
+    fn test_strong_count_widens_and_saturates() {
+        let narrow = Rime::<u8, str>::new("narrow");
+        let clones: Vec<_> = (0..254).map(|_| narrow.clone()).collect();
+        assert_eq!(narrow.strong_count(), 255); // The counter's maximum, read as a `usize`
+
+        let overflow = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| narrow.clone()));
+        assert!(overflow.is_err());
+        assert_eq!(narrow.strong_count(), 255); // Refused, not wrapped to zero
+        drop(clones);
+        assert!(narrow.strong_count().is_one());
+
+        let medium = Rime::<u32, [u8]>::new(&[1]);
+        let clones = [medium.clone(), medium.clone()];
+        assert_eq!(medium.strong_count(), 3);
+        drop(clones);
+
+        let narrow = Rime::<AtomicU8, str>::new("narrow");
+        let clones: Vec<_> = (0..126).map(|_| narrow.clone()).collect();
+        assert_eq!(narrow.strong_count(), 127); // Half the range, like `Arc`
+        let overflow = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| narrow.clone()));
+        assert!(overflow.is_err());
+        assert_eq!(narrow.strong_count(), 127); // The refused add was undone
+        let overflow = std::panic::catch_unwind(|| unsafe { (*narrow.counter_ptr()).increment_by(200) });
+        assert!(overflow.is_err());
+        assert_eq!(narrow.strong_count(), 127);
+        drop(clones);
+        assert!(narrow.strong_count().is_one());
+
+        let mut wide = AtomicUsize::new(usize::MAX / 2);
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| wide.increment())).is_err());
+        assert_eq!(wide.count(), usize::MAX / 2);
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| wide.increment_by(usize::MAX))).is_err());
+        assert_eq!(wide.count(), usize::MAX / 2);
+
+        let atomic = Rime::<AtomicUsize, u64>::steal(0);
+        let clone = atomic.clone();
+        assert_eq!((atomic.strong_count().get(), clone.strong_count_acquire()), (2, 2));
+
+        assert_eq!(u128::MAX.count(), usize::MAX); // Wider than `usize`: saturates
+        assert_eq!(std::cell::Cell::new(u128::MAX).count_relaxed(), usize::MAX);
+    }
+
+    #[test]
+    fn test_upgrade_saturates() {
+        let strong = Rime::<crate::WithWeak<u8>, str>::new("strong");
+        let clones: Vec<_> = (0..254).map(|_| strong.clone()).collect();
+        let weak = strong.downgrade();
+
+        let overflow = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| weak.upgrade()));
+        assert!(overflow.is_err());
+        assert_eq!(strong.strong_count(), 255); // Refused, not wrapped to zero
+        drop(clones);
+        assert_eq!(weak.upgrade().as_deref(), Some("strong"));
+    }
+
+    #[test]
+    fn test_from_vec_moves_elements() {
+        use std::{cell::Cell, rc::Rc};
+
+        struct Tracked(Rc<Cell<(usize, usize)>>, String);
+        impl Clone for Tracked {
+            fn clone(&self) -> Self {
+                let (clones, drops) = self.0.get();
+                self.0.set((clones + 1, drops));
+                Self(self.0.clone(), self.1.clone())
+            }
+        }
+        impl Drop for Tracked {
             fn drop(&mut self) {
-                *self.0.borrow_mut() += 1;
+                let (clones, drops) = self.0.get();
+                self.0.set((clones, drops + 1));
             }
         }
 
-        let dropped = Rc::new(RefCell::new(0));
-        {
-            let counter = DropCounter(dropped.clone());
-            let r1 = Rime::<usize, _>::new(&counter);
-            let _r2 = r1.clone(); // two references
-        }
+        let events = Rc::new(Cell::new((0, 0)));
+        let items: Vec<_> = ["a", "bb", "ccc"].map(|text| Tracked(events.clone(), text.to_string())).into();
+        let buffers: Vec<_> = items.iter().map(|item| item.1.as_ptr()).collect();
 
-        assert_eq!(*dropped.borrow(), 1); // Dropped once after refcount hits zero
+        let mut rime = Rime::<u8, [Tracked]>::from_vec(items);
+        assert_eq!(events.get(), (0, 0)); // Nothing cloned or dropped on the way in
+        assert_eq!(rime.iter().map(|item| item.1.as_str()).collect::<Vec<_>>(), ["a", "bb", "ccc"]);
+        assert_eq!(rime.iter().map(|item| item.1.as_ptr()).collect::<Vec<_>>(), buffers);
+
+        unsafe { rime.drop_payload_only() }
+        assert_eq!(events.get(), (0, 3)); // Each element is dropped exactly once
+
+        let empty = Rime::<u8, [String]>::from_vec(Vec::with_capacity(8));
+        assert!(empty.is_empty());
     }
 
     #[test]
-    fn test_atomic_clone_thread_safe() {
-        use std::thread;
+    fn test_same_object_for_trait_objects() {
+        trait Shape { fn area(&self) -> u32; }
+        struct Square(u32);
+        impl Shape for Square { fn area(&self) -> u32 { self.0 * self.0 } }
 
-        let rime = Rime::<AtomicUsize, str>::new("multi");
-        let mut handles = vec![];
+        let square = Rime::<u8, dyn Shape>::new_unsize(Square(3));
+        let clone = square.clone();
+        let other = Rime::<u8, dyn Shape>::new_unsize(Square(3));
 
-        for _ in 0..10 {
-            let cloned = rime.clone();
-            handles.push(thread::spawn(move || {
-                assert_eq!(&*cloned, "multi");
-            }));
+        assert_eq!(clone.area(), 9);
+        assert!(square.same_object(&clone) && square.same_pointer(&clone));
+        assert!(square == clone);
+        assert!(!square.same_object(&other) && !square.same_pointer(&other));
+        assert!(square != other);
+
+        let bytes = Rime::<u8, [u8]>::new(b"abc");
+        assert!(bytes.same_object(&bytes.clone()) && bytes.same_pointer(&bytes.clone()));
+    }
+
+    #[test]
+    fn test_array_payload() {
+        const WORD: usize = size_of::<usize>();
+        assert_eq!(size_of::<Rime<u8, [u32; 4]>>(), 2 * WORD);
+        assert_eq!(size_of::<Rime<u8, [u32]>>(), 3 * WORD);
+
+        let array = Rime::<u8, [u32; 4]>::from_array([1, 2, 3, 4]);
+        let clone = array.clone();
+        let layout = array.layout();
+
+        let slice = array.into_slice();
+        assert_eq!(&*slice, &[1, 2, 3, 4]);
+        assert_eq!((slice.layout(), slice.strong_count().get()), (layout, 2));
+        assert_eq!(slice.as_ptr() as *const u32, clone.as_ptr() as *const u32);
+
+        drop(clone);
+        assert!(slice.is_unique());
+    }
+
+    #[test]
+    fn test_counter_compare_exchange() {
+        fn increment_nonzero<C: Counter>(counter: &mut C) -> bool {
+            let mut current = counter.count();
+            loop {
+                if current == 0 { return false }
+                match counter.compare_exchange(current, current + 1) {
+                    Ok(_) => return true,
+                    Err(actual) => current = actual,
+                }
+            }
         }
 
-        for handle in handles {
-            handle.join().unwrap();
+        fn check<C: Counter>() {
+            let mut counter = C::new();
+            assert_eq!(counter.compare_exchange(2, 5), Err(1));
+            assert!(increment_nonzero(&mut counter));
+            assert_eq!(counter.count(), 2);
+            assert_eq!(counter.compare_exchange(2, 0), Ok(2));
+            assert!(!increment_nonzero(&mut counter)); // Rejected once the count is zero
+            assert_eq!(counter.count(), 0);
         }
 
-        assert_eq!(&*rime, "multi");
+        check::<u8>();
+        check::<std::cell::Cell<u32>>();
+        check::<AtomicUsize>();
+        check::<AtomicU8>();
+        check::<crate::WithWeak<usize>>();
+        check::<crate::CachePadded<AtomicU16>>();
+
+        struct Emulated(usize); // Relies on the default implementation
+        impl Counter for Emulated {
+            fn new() -> Self { Self(1) }
+            fn increment(&mut self) { self.0 += 1 }
+            fn decrement(&mut self) -> bool { self.0 -= 1; self.0 == 0 }
+            fn try_increment_nonzero(&mut self) -> bool { self.0 != 0 && { self.0 += 1; true } }
+            fn count(&self) -> usize { self.0 }
+        }
+        check::<Emulated>();
+
+        assert_eq!(Counter::compare_exchange(&mut AtomicU8::new(1), 300, 1), Err(1)); // Unrepresentable current
     }
 
+    #[cfg(debug_assertions)]
     #[test]
-    fn test_as_ref_and_conversion() {
-        let rime = Rime::<u8, str>::new("as_ref test");
-        let rime2: Rime<u8, str> = (&rime).into();
-
-        assert_eq!(rime.as_ref(), rime2.as_ref());
+    #[should_panic(expected = "Rime::as_mut_ptr called on a shared allocation (strong count 2)")]
+    fn test_as_mut_ptr_rejects_shared_in_debug() {
+        let rime = Rime::<u8, [u8]>::new(b"shared");
+        let _clone = rime.clone();
+        let _ = rime.as_mut_ptr();
     }
 }
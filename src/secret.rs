@@ -0,0 +1,148 @@
+use std::{alloc::Layout, mem::ManuallyDrop, ptr::write_volatile, sync::atomic::{Ordering, compiler_fence}};
+
+use crate::{heap::*, rime::*, track::*};
+
+/// A shared byte buffer that is zeroed when its last strong reference is dropped.
+///
+/// `Secret` wraps a `Rime<C, [u8]>` holding sensitive data (keys, tokens, passwords). When the
+/// strong count reaches zero, payloads of at least `MIN_LEN` bytes are overwritten with zeroes
+/// before the block is returned to the allocator. The writes are volatile, so the compiler cannot
+/// elide them as dead stores. Smaller payloads are freed without the extra pass, which makes
+/// `MIN_LEN` a cost threshold; the default of `0` zeroes every payload. When poisoning is on,
+/// the block is then filled with `0xDE` like any other freed `Rime` block.
+///
+/// Copies made before wrapping (e.g. the source slice passed to [`Rime::new`]) are not covered.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+/// use kroos::{Rime, Secret};
+///
+/// let key = Secret::<AtomicUsize>::new(Rime::new(b"hunter2"));
+/// let shared = key.clone();
+/// assert_eq!(&*shared, b"hunter2");
+/// assert_eq!(format!("{key:?}"), "Secret([REDACTED; 7])");
+/// ```
+pub struct Secret<C: Counter, const MIN_LEN: usize = 0>(ManuallyDrop<Rime<C, [u8]>>);
+
+impl<C: Counter, const MIN_LEN: usize> Secret<C, MIN_LEN> {
+    /// Takes ownership of a byte `Rime`, zeroing it once the last `Secret` clone is dropped.
+    ///
+    /// Other plain `Rime` clones of the same allocation free it without zeroing, so wrap the
+    /// value before sharing it.
+    #[inline(always)]
+    pub fn new(rime: Rime<C, [u8]>) -> Self {
+        Self(ManuallyDrop::new(rime))
+    }
+
+    /// Returns the bytes mutably if this is the only reference, e.g. to fill a buffer from
+    /// [`Rime::new_zeroed_secret`].
+    #[inline]
+    pub fn get_mut(&mut self) -> Option<&mut [u8]> {
+        if !self.0.is_unique() { return None }
+        Some(unsafe { &mut *self.0.as_mut_ptr() })
+    }
+}
+
+impl<C: Counter> Rime<C, [u8]> {
+    /// Allocates a zero-filled buffer of `len` bytes and wraps it in a [`Secret`], so it is
+    /// zeroed both at allocation and when the last clone is dropped.
+    ///
+    /// A plain `Rime` never clears its payload on free, hence the `Secret` return type. Fill
+    /// it through [`Secret::get_mut`] before sharing it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::atomic::AtomicUsize;
+    /// use kroos::Rime;
+    ///
+    /// let mut key = Rime::<AtomicUsize, [u8]>::new_zeroed_secret(4);
+    /// assert_eq!(&*key, &[0; 4]);
+    /// key.get_mut().unwrap().copy_from_slice(b"k3y!");
+    /// assert_eq!(&*key.clone(), b"k3y!");
+    /// ```
+    pub fn new_zeroed_secret(len: usize) -> Secret<C> {
+        unsafe {
+            let (counter_ptr, inner_ptr) = allocate_block::<C>(Layout::array::<u8>(len).expect("Rime layout overflow"));
+            inner_ptr.write_bytes(0, len);
+            Secret::new(Rime::from_raw_parts(counter_ptr, inner_ptr, len))
+        }
+    }
+}
+
+impl<C: Counter, const MIN_LEN: usize> Clone for Secret<C, MIN_LEN> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new((*self.0).clone())
+    }
+}
+
+impl<C: Counter, const MIN_LEN: usize> std::ops::Deref for Secret<C, MIN_LEN> {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<C: Counter, const MIN_LEN: usize> std::fmt::Debug for Secret<C, MIN_LEN> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Secret([REDACTED; {}])", self.0.len())
+    }
+}
+
+impl<C: Counter, const MIN_LEN: usize> Drop for Secret<C, MIN_LEN> {
+    fn drop(&mut self) {
+        let rime = &*self.0;
+        let counter_ptr = rime.counter_ptr();
+
+        unsafe {
+            if !(*counter_ptr).decrement() { return }
+
+            if rime.len() >= MIN_LEN {
+                let data = rime.as_ptr() as *mut u8; // The count is already zero
+                for i in 0..rime.len() {
+                    write_volatile(data.add(i), 0);
+                }
+                compiler_fence(Ordering::SeqCst);
+            }
+
+            if (*counter_ptr).release() {
+                let layout = rime.layout();
+                poison(counter_ptr.cast(), layout);
+                deallocate(counter_ptr.cast(), layout);
+                track_free();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use super::*;
+
+    #[test]
+    fn secret_shares_and_redacts() {
+        let secret = Secret::<Cell<u8>, 16>::new(Rime::new(b"short"));
+        let clone = secret.clone();
+
+        assert_eq!(&*clone, b"short");
+        assert_eq!(secret.0.strong_count(), 2);
+        drop(secret);
+        assert_eq!(clone.0.strong_count(), 1);
+        assert_eq!(format!("{clone:?}"), "Secret([REDACTED; 5])");
+    }
+
+    #[test]
+    fn zeroed_secret_is_writable_while_unique() {
+        let mut secret = Rime::<Cell<u8>, [u8]>::new_zeroed_secret(3);
+        assert_eq!(&*secret, &[0, 0, 0]);
+
+        secret.get_mut().unwrap()[1] = 7;
+        let clone = secret.clone();
+        assert!(secret.get_mut().is_none());
+        assert_eq!(&*clone, &[0, 7, 0]);
+    }
+}
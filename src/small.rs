@@ -0,0 +1,175 @@
+use crate::{Counter, Rime};
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for str {}
+    impl Sealed for [u8] {}
+}
+
+/// A byte-string payload that [`SmallRime`] can keep inline: `str` or `[u8]`.
+///
+/// Sealed; the inline buffer is plain bytes, so only types that are plain bytes qualify.
+pub trait SmallPayload: sealed::Sealed {
+    #[doc(hidden)]
+    fn as_bytes(&self) -> &[u8];
+
+    /// # Safety
+    /// `bytes` must be a valid `Self` (valid UTF-8 for `str`).
+    #[doc(hidden)]
+    unsafe fn from_bytes(bytes: &[u8]) -> &Self;
+}
+
+impl SmallPayload for str {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    #[inline(always)]
+    unsafe fn from_bytes(bytes: &[u8]) -> &Self {
+        std::str::from_utf8_unchecked(bytes)
+    }
+}
+
+impl SmallPayload for [u8] {
+    #[inline(always)]
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    #[inline(always)]
+    unsafe fn from_bytes(bytes: &[u8]) -> &Self {
+        bytes
+    }
+}
+
+/// A shared `str` or `[u8]` that stores payloads of up to `N` bytes inline and larger ones in
+/// a [`Rime`].
+///
+/// Short values never touch the allocator: they are copied into the handle itself, and so are
+/// their clones. Longer values spill to a `Rime`, whose clones share one block. Either way the
+/// value dereferences to `T`, so callers need not know which storage was picked.
+///
+/// The handle is `N` bytes plus a length and a tag, so keep `N` near the size of a `Rime`
+/// handle (two or three words) unless short strings dominate.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+/// use kroos::SmallRime;
+///
+/// let short = SmallRime::<AtomicUsize, str, 16>::new("inline");
+/// let long = SmallRime::<AtomicUsize, str, 16>::new("this one spills to the heap");
+///
+/// assert!(short.is_inline() && !long.is_inline());
+/// assert_eq!((&*short, &*long), ("inline", "this one spills to the heap"));
+/// ```
+pub struct SmallRime<C: Counter, T: ?Sized + SmallPayload, const N: usize> {
+    repr: Repr<C, T, N>,
+}
+
+enum Repr<C: Counter, T: ?Sized, const N: usize> {
+    Inline { len: usize, bytes: [u8; N] },
+    Heap(Rime<C, T>),
+}
+
+impl<C: Counter, T: ?Sized + SmallPayload, const N: usize> SmallRime<C, T, N> {
+    /// Copies `value` inline if it fits in `N` bytes, or into a new `Rime` otherwise.
+    pub fn new(value: &T) -> Self {
+        let source = value.as_bytes();
+        if source.len() > N {
+            return Self { repr: Repr::Heap(Rime::new(value)) };
+        }
+
+        let mut bytes = [0; N];
+        bytes[..source.len()].copy_from_slice(source);
+        Self { repr: Repr::Inline { len: source.len(), bytes } }
+    }
+
+    /// Returns `true` if the payload is stored in the handle rather than in a `Rime`.
+    #[inline(always)]
+    pub fn is_inline(&self) -> bool {
+        matches!(self.repr, Repr::Inline { .. })
+    }
+
+    /// Returns the backing `Rime`, or `None` if the payload is inline.
+    #[inline]
+    pub fn as_rime(&self) -> Option<&Rime<C, T>> {
+        match &self.repr {
+            Repr::Inline { .. } => None,
+            Repr::Heap(rime) => Some(rime),
+        }
+    }
+}
+
+/// Adopts the `Rime` as is, even if its payload would fit inline.
+impl<C: Counter, T: ?Sized + SmallPayload, const N: usize> From<Rime<C, T>> for SmallRime<C, T, N> {
+    #[inline(always)]
+    fn from(rime: Rime<C, T>) -> Self {
+        Self { repr: Repr::Heap(rime) }
+    }
+}
+
+impl<C: Counter, T: ?Sized + SmallPayload, const N: usize> Clone for SmallRime<C, T, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        let repr = match &self.repr {
+            Repr::Inline { len, bytes } => Repr::Inline { len: *len, bytes: *bytes },
+            Repr::Heap(rime) => Repr::Heap(rime.clone()),
+        };
+        Self { repr }
+    }
+}
+
+impl<C: Counter, T: ?Sized + SmallPayload, const N: usize> std::ops::Deref for SmallRime<C, T, N> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        match &self.repr {
+            Repr::Inline { len, bytes } => unsafe { T::from_bytes(bytes.get_unchecked(..*len)) },
+            Repr::Heap(rime) => rime,
+        }
+    }
+}
+
+impl<C: Counter, T: ?Sized + SmallPayload, const N: usize> AsRef<T> for SmallRime<C, T, N> {
+    #[inline(always)]
+    fn as_ref(&self) -> &T {
+        self
+    }
+}
+
+impl<C: Counter, T: ?Sized + SmallPayload + std::fmt::Debug, const N: usize> std::fmt::Debug for SmallRime<C, T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use super::*;
+
+    #[test]
+    fn small_rime_storage() {
+        let bytes = SmallRime::<Cell<u8>, [u8], 4>::new(b"four");
+        let copy = bytes.clone();
+        assert!(copy.is_inline() && copy.as_rime().is_none());
+        assert_eq!(&*copy, b"four");
+
+        let spilled = SmallRime::<Cell<u8>, [u8], 4>::new(b"five!");
+        let shared = spilled.clone();
+        assert!(!shared.is_inline());
+        assert_eq!(shared.as_rime().unwrap().strong_count(), 2);
+        assert_eq!(format!("{shared:?}"), format!("{:?}", b"five!"));
+
+        let empty = SmallRime::<Cell<u8>, str, 0>::new("");
+        assert!(empty.is_inline());
+        assert_eq!(&*empty, "");
+
+        let adopted = SmallRime::<Cell<u8>, str, 8>::from(Rime::new("tiny"));
+        assert!(!adopted.is_inline());
+        assert_eq!(adopted.as_ref(), "tiny");
+    }
+}
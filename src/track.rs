@@ -0,0 +1,41 @@
+#[cfg(feature = "track-allocations")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "track-allocations")]
+static LIVE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the number of [`Rime`](crate::Rime) and [`Flake`](crate::Flake) allocations currently alive.
+///
+/// Every allocation (or adoption through `Flake::from_raw` or `Rime::from_raw_with_layout`) increments the count and every
+/// deallocation decrements it, so test suites can assert that a scope released everything it allocated.
+/// Handles produced by `into_handle` keep their allocation alive and are still counted.
+///
+/// Only available with the `track-allocations` feature; without it the bookkeeping compiles out entirely.
+///
+/// # Example
+/// ```
+/// use kroos::{Flake, live_allocations};
+///
+/// let before = live_allocations();
+/// let flake = Flake::new("tracked");
+/// assert_eq!(live_allocations(), before + 1);
+/// drop(flake);
+/// assert_eq!(live_allocations(), before);
+/// ```
+#[cfg(feature = "track-allocations")]
+#[inline]
+pub fn live_allocations() -> usize {
+    LIVE_ALLOCATIONS.load(Ordering::Acquire)
+}
+
+#[inline(always)]
+pub(crate) fn track_alloc() {
+    #[cfg(feature = "track-allocations")]
+    LIVE_ALLOCATIONS.fetch_add(1, Ordering::Release);
+}
+
+#[inline(always)]
+pub(crate) fn track_free() {
+    #[cfg(feature = "track-allocations")]
+    LIVE_ALLOCATIONS.fetch_sub(1, Ordering::Release);
+}
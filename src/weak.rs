@@ -0,0 +1,392 @@
+use std::{alloc::Layout, marker::PhantomData, ptr::{dangling, null_mut}};
+
+use crate::{heap::*, rime::*, track::*};
+
+/// A [`Counter`] that also tracks weak references to the allocation.
+///
+/// The weak count includes one implicit reference held collectively by all strong
+/// references, so the block outlives the last [`Rime`] while any [`Weak`] remains.
+///
+/// # Safety
+/// Implementors must ensure:
+/// - `increment_weak()` increases the weak count.
+/// - `decrement_weak()` decreases it and returns `true` if it reached zero, with the same
+///   `Release`/`Acquire` fence pattern as [`Counter::decrement`] for atomic counters.
+/// - `weak_count()` returns the stored weak count, including the implicit reference.
+pub trait WeakCounter: Counter {
+    fn increment_weak(&mut self);
+    fn decrement_weak(&mut self) -> bool;
+    fn weak_count(&self) -> usize;
+}
+
+/// A [`Counter`] adapter pairing a strong and a weak count of the same counter type `A`.
+///
+/// Plain counters keep [`Rime`] lean; opt into [`Weak`] references by choosing `WithWeak<A>`
+/// as the counter, e.g. `Rime<WithWeak<AtomicUsize>, str>`. Both counts reuse `A`'s
+/// increment and decrement, including its memory ordering.
+///
+/// Weak support lives entirely in the counter: a `Rime` handle stays the same size (a counter
+/// pointer plus a payload pointer) whichever counter it uses, and only the shared block grows
+/// by one extra `A`. Counters without weak support pay nothing for it.
+///
+/// Teardown happens in two phases:
+/// 1. When the strong count reaches zero the payload is released (`Rime` never runs payload
+///    destructors, so nothing else happens) and the implicit weak reference is dropped.
+/// 2. When the weak count reaches zero the whole block is deallocated.
+#[derive(Debug)]
+pub struct WithWeak<A: Counter> {
+    strong: A,
+    weak: A,
+}
+
+impl<A: Counter> Counter for WithWeak<A> {
+    #[inline(always)]
+    fn new() -> Self {
+        Self { strong: A::new(), weak: A::new() }
+    }
+
+    #[inline(always)]
+    fn increment(&mut self) {
+        self.strong.increment()
+    }
+
+    #[inline(always)]
+    fn increment_by(&mut self, n: usize) {
+        self.strong.increment_by(n)
+    }
+
+    #[inline(always)]
+    fn decrement(&mut self) -> bool {
+        self.strong.decrement()
+    }
+
+    #[inline(always)]
+    fn decrement_by(&mut self, n: usize) -> bool {
+        self.strong.decrement_by(n)
+    }
+
+    #[inline(always)]
+    fn try_increment_nonzero(&mut self) -> bool {
+        self.strong.try_increment_nonzero()
+    }
+
+    #[inline(always)]
+    fn count(&self) -> usize {
+        self.strong.count()
+    }
+
+    #[inline(always)]
+    fn count_relaxed(&self) -> usize {
+        self.strong.count_relaxed()
+    }
+
+    #[inline(always)]
+    fn is_unique(&self) -> bool {
+        self.strong.count() == 1 && self.weak.count() == 1
+    }
+
+    #[inline(always)]
+    fn release(&mut self) -> bool {
+        self.weak.decrement()
+    }
+
+    #[inline(always)]
+    fn compare_exchange(&mut self, current: usize, new: usize) -> Result<usize, usize> {
+        self.strong.compare_exchange(current, new)
+    }
+}
+
+impl<A: Counter> WeakCounter for WithWeak<A> {
+    #[inline(always)]
+    fn increment_weak(&mut self) {
+        self.weak.increment()
+    }
+
+    #[inline(always)]
+    fn decrement_weak(&mut self) -> bool {
+        self.weak.decrement()
+    }
+
+    #[inline(always)]
+    fn weak_count(&self) -> usize {
+        self.weak.count()
+    }
+}
+
+/// A non-owning reference to a [`Rime`] allocation.
+///
+/// A `Weak` keeps the block alive but not the value: [`Weak::upgrade`] only succeeds while at
+/// least one strong `Rime` exists. Obtain one with [`Rime::downgrade`], or create a dangling
+/// placeholder that never upgrades with [`Weak::new`].
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::AtomicUsize;
+/// use kroos::{Rime, WithWeak};
+///
+/// let rime = Rime::<WithWeak<AtomicUsize>, str>::new("weak");
+/// let weak = rime.downgrade();
+///
+/// assert_eq!(weak.upgrade().as_deref(), Some("weak"));
+/// drop(rime);
+/// assert!(weak.upgrade().is_none());
+/// ```
+pub struct Weak<C: WeakCounter, T: ?Sized> {
+    _marker: PhantomData<(C, T)>,
+    counter_ptr: *mut C,
+    inner_ptr: *const T,
+}
+
+impl<C: WeakCounter, T: ?Sized> Rime<C, T> {
+    /// Creates a [`Weak`] reference to this allocation.
+    #[inline]
+    pub fn downgrade(&self) -> Weak<C, T> {
+        unsafe { (*self.counter_ptr()).increment_weak() }
+        Weak { _marker: PhantomData, counter_ptr: self.counter_ptr(), inner_ptr: self.as_ptr() }
+    }
+
+    /// Returns the number of [`Weak`] references to this allocation.
+    #[inline]
+    pub fn weak_count(&self) -> usize {
+        unsafe { (*self.counter_ptr()).weak_count() - 1 }
+    }
+
+    /// Asserts that this allocation has exactly `strong` strong and `weak` [`Weak`] references.
+    ///
+    /// A test helper: both counts are read without synchronization, so only use it where no
+    /// other thread is cloning or dropping handles.
+    ///
+    /// # Panics
+    /// Panics with both the expected and the actual counts if either one differs.
+    ///
+    /// # Example
+    /// ```
+    /// use std::cell::Cell;
+    /// use kroos::{Rime, WithWeak};
+    ///
+    /// let rime = Rime::<WithWeak<Cell<u8>>, str>::new("counted");
+    /// let _weak = rime.downgrade();
+    /// rime.assert_counts(1, 1);
+    /// ```
+    #[track_caller]
+    pub fn assert_counts(&self, strong: usize, weak: usize) {
+        let actual = (self.strong_count().get(), self.weak_count());
+        assert!(
+            actual == (strong, weak),
+            "Rime count mismatch: expected {strong} strong and {weak} weak, found {} strong and {} weak",
+            actual.0, actual.1,
+        );
+    }
+}
+
+impl<C: WeakCounter, T> Weak<C, T> {
+    /// Creates a dangling `Weak` that is not tied to any allocation.
+    ///
+    /// It never upgrades and dropping it does nothing, which makes it a placeholder for fields
+    /// initialized before the strong value exists, like [`std::sync::Weak::new`].
+    ///
+    /// # Example
+    /// ```
+    /// use kroos::{Weak, WithWeak};
+    ///
+    /// let weak = Weak::<WithWeak<u32>, String>::new();
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self { _marker: PhantomData, counter_ptr: null_mut(), inner_ptr: dangling() }
+    }
+}
+
+impl<C: WeakCounter, T> Default for Weak<C, T> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: WeakCounter, T: ?Sized> Weak<C, T> {
+    /// Attempts to obtain a strong [`Rime`], returning `None` if the value was already released.
+    ///
+    /// The strong count is only incremented if it is still nonzero, so a value whose last
+    /// strong reference is being dropped concurrently can never be resurrected.
+    #[inline]
+    pub fn upgrade(&self) -> Option<Rime<C, T>> {
+        if self.counter_ptr.is_null() { return None }
+        unsafe {
+            (*self.counter_ptr).try_increment_nonzero()
+                .then(|| Rime::from_raw(self.counter_ptr, self.inner_ptr))
+        }
+    }
+
+    /// Returns the number of strong [`Rime`]s pointing to the allocation.
+    #[inline]
+    pub fn strong_count(&self) -> usize {
+        if self.counter_ptr.is_null() { return 0 }
+        unsafe { (*self.counter_ptr).count() }
+    }
+
+    /// Returns the number of `Weak` references, or `0` once no strong reference remains.
+    #[inline]
+    pub fn weak_count(&self) -> usize {
+        if self.counter_ptr.is_null() { return 0 }
+        unsafe {
+            let counter = &*self.counter_ptr;
+            if counter.count() == 0 { 0 } else { counter.weak_count() - 1 }
+        }
+    }
+}
+
+impl<C: WeakCounter, T: ?Sized> Clone for Weak<C, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        if !self.counter_ptr.is_null() {
+            unsafe { (*self.counter_ptr).increment_weak() }
+        }
+        Self { _marker: PhantomData, counter_ptr: self.counter_ptr, inner_ptr: self.inner_ptr }
+    }
+}
+
+impl<C: WeakCounter, T: ?Sized> Drop for Weak<C, T> {
+    fn drop(&mut self) {
+        if self.counter_ptr.is_null() { return }
+        unsafe {
+            if (*self.counter_ptr).decrement_weak() {
+                let (layout, _) = block_layout::<C>(Layout::for_value_raw(self.inner_ptr));
+                poison(self.counter_ptr.cast(), layout);
+                deallocate(self.counter_ptr.cast(), layout);
+                track_free();
+            }
+        }
+    }
+}
+
+impl<C: WeakCounter, T: ?Sized> std::fmt::Debug for Weak<C, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("(Weak)")
+    }
+}
+
+unsafe impl<C: WeakCounter + Send, T: ?Sized + Send> Send for Weak<C, T> {}
+unsafe impl<C: WeakCounter + Sync, T: ?Sized + Sync> Sync for Weak<C, T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, sync::atomic::AtomicUsize, thread};
+    use super::*;
+
+    #[test]
+    fn handle_sizes() {
+        use std::mem::size_of;
+
+        const WORD: usize = size_of::<usize>();
+        assert_eq!(size_of::<Rime<usize, u64>>(), 2 * WORD);
+        assert_eq!(size_of::<Rime<WithWeak<usize>, u64>>(), 2 * WORD);
+        assert_eq!(size_of::<Weak<WithWeak<usize>, u64>>(), 2 * WORD);
+
+        // Unsized payloads add their metadata, with or without weak support
+        assert_eq!(size_of::<Rime<AtomicUsize, str>>(), 3 * WORD);
+        assert_eq!(size_of::<Rime<WithWeak<AtomicUsize>, str>>(), 3 * WORD);
+
+        // The extra weak count is paid for once, in the shared block
+        assert_eq!(size_of::<WithWeak<AtomicUsize>>(), 2 * size_of::<AtomicUsize>());
+        let plain = Rime::<AtomicUsize, u64>::steal(0);
+        let weak = Rime::<WithWeak<AtomicUsize>, u64>::steal(0);
+        assert_eq!(weak.layout().size(), plain.layout().size() + WORD);
+    }
+
+    #[test]
+    fn weak_upgrade_and_counts() {
+        let rime = Rime::<WithWeak<Cell<u32>>, [u8]>::new(&[1, 2, 3]);
+        let weak = rime.downgrade();
+        let weak2 = weak.clone();
+
+        assert_eq!((rime.strong_count().get(), rime.weak_count()), (1, 2));
+        assert!(!rime.is_unique()); // A weak reference could upgrade
+
+        let upgraded = weak.upgrade().unwrap();
+        assert_eq!(&*upgraded, &[1, 2, 3]);
+        assert_eq!(weak.strong_count(), 2);
+
+        drop((rime, upgraded));
+        assert!(weak2.upgrade().is_none());
+        assert_eq!((weak.strong_count(), weak.weak_count()), (0, 0));
+    }
+
+    #[test]
+    fn assert_counts_tracks_clone_and_downgrade() {
+        let rime = Rime::<WithWeak<AtomicUsize>, str>::new("counts");
+        rime.assert_counts(1, 0);
+
+        let clone = rime.clone();
+        let weak = rime.downgrade();
+        rime.assert_counts(2, 1);
+
+        let upgraded = weak.upgrade().unwrap();
+        drop(weak);
+        upgraded.assert_counts(3, 0);
+        drop((clone, upgraded));
+        rime.assert_counts(1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Rime count mismatch: expected 1 strong and 0 weak, found 1 strong and 1 weak")]
+    fn assert_counts_reports_mismatch() {
+        let rime = Rime::<WithWeak<Cell<u8>>, [u8]>::new(&[0]);
+        let _weak = rime.downgrade();
+        rime.assert_counts(1, 0);
+    }
+
+    #[test]
+    fn weak_new_is_dangling() {
+        let weak = Weak::<WithWeak<AtomicUsize>, [u8; 4]>::new();
+        let cloned = weak.clone();
+
+        assert!(weak.upgrade().is_none());
+        assert_eq!((cloned.strong_count(), cloned.weak_count()), (0, 0));
+        drop((weak, cloned));
+        drop(Weak::<WithWeak<u8>, String>::default());
+    }
+
+    #[test]
+    fn weak_outlived_by_strong() {
+        let rime = Rime::<WithWeak<usize>, str>::new("strong");
+        drop(rime.downgrade());
+
+        assert_eq!(rime.weak_count(), 0);
+        assert!(rime.is_unique());
+    }
+
+    #[test]
+    fn weak_concurrent_teardown() {
+        for _ in 0..32 {
+            let rime = Rime::<WithWeak<AtomicUsize>, [u64]>::new(&[7; 16]);
+            let weak = rime.downgrade();
+
+            thread::scope(|scope| {
+                for _ in 0..4 {
+                    let strong = rime.clone();
+                    let weak = weak.clone();
+                    scope.spawn(move || {
+                        for _ in 0..200 {
+                            let extra = strong.downgrade();
+                            if let Some(upgraded) = extra.upgrade() {
+                                assert_eq!(upgraded[15], 7);
+                            }
+                        }
+                        drop(strong);
+                        while let Some(upgraded) = weak.upgrade() {
+                            assert_eq!(upgraded[0], 7);
+                            drop(upgraded);
+                            thread::yield_now();
+                        }
+                    });
+                }
+                drop(rime);
+            });
+
+            assert!(weak.upgrade().is_none());
+            assert_eq!(weak.weak_count(), 0);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::ops::Range;
+
+use crate::{Counter, Rime};
+
+/// A shared, zero-copy view of a sub-range of a slice [`Rime`].
+///
+/// Created by [`Rime::subslice`]. The window holds one strong reference to the whole block, so
+/// the elements stay valid for as long as any window or clone exists, even after the original
+/// `Rime` is dropped. When the last reference goes away the full block is freed with its
+/// original layout.
+///
+/// A window is its own type rather than a `Rime<C, [T]>` because a `Rime` derives its block
+/// layout from its own payload pointer: a narrowed pointer would free the wrong size. The
+/// window keeps the full `Rime` alongside the range instead, at the cost of two extra words.
+///
+/// # Example
+/// ```
+/// use kroos::Rime;
+///
+/// let packet = Rime::<u8, [u8]>::new(b"HDRpayload");
+/// let body = packet.subslice(3..10);
+/// drop(packet);
+///
+/// assert_eq!(&*body, b"payload");
+/// ```
+pub struct RimeWindow<C: Counter, T> {
+    rime: Rime<C, [T]>,
+    start: usize,
+    len: usize,
+}
+
+impl<C: Counter, T> Rime<C, [T]> {
+    /// Returns a window onto `range` of the slice that shares this allocation.
+    ///
+    /// No elements are copied; the window increments the reference count.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds or decreasing, like slice indexing.
+    pub fn subslice(&self, range: Range<usize>) -> RimeWindow<C, T> {
+        let len = self[range.clone()].len();
+        RimeWindow { rime: self.clone(), start: range.start, len }
+    }
+}
+
+impl<C: Counter, T> RimeWindow<C, T> {
+    /// Returns a narrower window; `range` is relative to this window.
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds or decreasing, like slice indexing.
+    pub fn subslice(&self, range: Range<usize>) -> Self {
+        let len = self[range.clone()].len();
+        Self { rime: self.rime.clone(), start: self.start + range.start, len }
+    }
+
+    /// Returns the range of the window within the full slice.
+    #[inline(always)]
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.start + self.len
+    }
+
+    /// Returns the `Rime` holding the full slice this window points into.
+    #[inline(always)]
+    pub fn source(&self) -> &Rime<C, [T]> {
+        &self.rime
+    }
+}
+
+impl<C: Counter, T> Clone for RimeWindow<C, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self { rime: self.rime.clone(), start: self.start, len: self.len }
+    }
+}
+
+impl<C: Counter, T> std::ops::Deref for RimeWindow<C, T> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &[T] {
+        unsafe { self.rime.get_unchecked(self.start..self.start + self.len) }
+    }
+}
+
+impl<C: Counter, T> AsRef<[T]> for RimeWindow<C, T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<C: Counter, T: std::fmt::Debug> std::fmt::Debug for RimeWindow<C, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use super::*;
+
+    #[test]
+    fn window_outlives_original() {
+        let rime = Rime::<AtomicUsize, [u32]>::new(&[0, 1, 2, 3, 4, 5]);
+        let window = rime.subslice(1..5);
+        let inner = window.subslice(1..3);
+
+        assert_eq!(&*window, &[1, 2, 3, 4]);
+        assert_eq!((&*inner, inner.range()), (&[2, 3][..], 2..4));
+        assert_eq!(window.as_ptr(), unsafe { (rime.as_ptr() as *const u32).add(1) });
+        assert_eq!(rime.strong_count(), 3);
+
+        drop(rime);
+        let clone = inner.clone();
+        drop((window, inner));
+        assert_eq!(&*clone, &[2, 3]); // Still valid through the last handle
+        assert_eq!(clone.source().strong_count(), 1);
+        assert_eq!(clone.source().len(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn window_out_of_bounds() {
+        let rime = Rime::<u8, [u8]>::new(b"abc");
+        let _ = rime.subslice(2..4);
+    }
+}
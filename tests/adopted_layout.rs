@@ -0,0 +1,74 @@
+use std::{alloc::{GlobalAlloc, Layout, System, alloc}, sync::{Mutex, atomic::{AtomicU32, AtomicUsize, Ordering}}};
+use kroos::{Flake, Rime};
+
+/// Records the layout the watched block is freed with.
+struct Recording;
+
+static WATCHED: AtomicUsize = AtomicUsize::new(0);
+static FREED: Mutex<Option<Layout>> = Mutex::new(None);
+
+/// Serializes the tests, which share the watched block.
+static LOCK: Mutex<()> = Mutex::new(());
+
+unsafe impl GlobalAlloc for Recording {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr as usize == WATCHED.load(Ordering::Relaxed) {
+            *FREED.lock().unwrap() = Some(layout);
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Recording = Recording;
+
+fn watch(block: *mut u8) {
+    *FREED.lock().unwrap() = None;
+    WATCHED.store(block as usize, Ordering::Relaxed);
+}
+
+fn freed() -> Option<Layout> {
+    *FREED.lock().unwrap()
+}
+
+#[test]
+fn rime_frees_with_the_adopted_layout() {
+    let _guard = LOCK.lock().unwrap();
+    let layout = Layout::from_size_align(96, 64).unwrap();
+
+    let rime = unsafe {
+        let block = alloc(layout);
+        block.cast::<AtomicU32>().write(AtomicU32::new(1));
+        block.add(32).copy_from_nonoverlapping(b"adopted".as_ptr(), 7);
+        watch(block);
+        Rime::<AtomicU32, [u8]>::from_raw_with_layout(block.cast(), std::ptr::slice_from_raw_parts(block.add(32), 7), layout)
+    };
+    let clone = rime.clone();
+
+    drop(rime);
+    assert_eq!(freed(), None); // `clone` keeps the block alive
+    assert_eq!(&*clone, b"adopted");
+    drop(clone);
+    assert_eq!(freed(), Some(layout));
+}
+
+#[test]
+fn flake_frees_with_the_adopted_layout() {
+    let _guard = LOCK.lock().unwrap();
+    let layout = Layout::from_size_align(24, 32).unwrap();
+
+    let flake = unsafe {
+        let block = alloc(layout);
+        block.cast::<u64>().write(7);
+        watch(block);
+        Flake::from_raw_with_layout(block.cast::<u64>(), layout)
+    };
+
+    assert_eq!(*flake, 7);
+    drop(flake);
+    assert_eq!(freed(), Some(layout));
+}
@@ -0,0 +1,8 @@
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    // A passing case makes trybuild run `cargo build`, which evaluates the post-monomorphization
+    // `const` assertions that `cargo check` would skip.
+    cases.pass("tests/compile_pass/*.rs");
+    cases.compile_fail("tests/compile_fail/*.rs");
+}
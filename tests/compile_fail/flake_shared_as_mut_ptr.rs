@@ -0,0 +1,7 @@
+use kroos::Flake;
+
+fn main() {
+    let flake = Flake::new(&[1u8, 2, 3][..]);
+    let shared = &flake;
+    let _ = shared.as_mut_ptr();
+}
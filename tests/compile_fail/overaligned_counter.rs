@@ -0,0 +1,16 @@
+use kroos::{Counter, Rime};
+
+#[repr(align(8192))]
+struct Overaligned(usize);
+
+impl Counter for Overaligned {
+    fn new() -> Self { Overaligned(1) }
+    fn increment(&mut self) { self.0 += 1 }
+    fn decrement(&mut self) -> bool { self.0 -= 1; self.0 == 0 }
+    fn try_increment_nonzero(&mut self) -> bool { self.0 += 1; true }
+    fn count(&self) -> usize { self.0 }
+}
+
+fn main() {
+    let _ = Rime::<Overaligned, u8>::steal(1);
+}
@@ -0,0 +1,17 @@
+use kroos::{Counter, Rime};
+
+#[repr(align(4096))]
+struct PageAligned(usize);
+
+impl Counter for PageAligned {
+    fn new() -> Self { PageAligned(1) }
+    fn increment(&mut self) { self.0 += 1 }
+    fn decrement(&mut self) -> bool { self.0 -= 1; self.0 == 0 }
+    fn try_increment_nonzero(&mut self) -> bool { self.0 += 1; true }
+    fn count(&self) -> usize { self.0 }
+}
+
+fn main() {
+    let rime = Rime::<PageAligned, u8>::steal(1);
+    assert_eq!(*rime, 1);
+}
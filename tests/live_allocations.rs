@@ -0,0 +1,62 @@
+#![cfg(feature = "track-allocations")]
+
+use std::{alloc::{Layout, alloc}, sync::atomic::AtomicUsize};
+use kroos::{Flake, Noop, Rime, live_allocations};
+
+#[test]
+fn live_allocations_return_to_zero() {
+    assert_eq!(live_allocations(), 0);
+
+    {
+        let flake = Flake::new("flake");
+        let stolen = Flake::steal(42u64);
+        let rime = Rime::<AtomicUsize, [u8]>::new(&[1, 2, 3]);
+        let cloned = rime.clone();
+        let sized = Rime::<u8, String>::steal(String::new());
+
+        assert_eq!(live_allocations(), 4); // Clones share one allocation
+        drop((flake, stolen, rime));
+        assert_eq!(live_allocations(), 2); // `cloned` keeps the block alive
+        drop((cloned, sized));
+    }
+
+    let handle = Flake::new("handle").into_handle();
+    assert_eq!(live_allocations(), 1);
+    drop(unsafe { Flake::<str>::from_handle(handle) });
+
+    let literal = Rime::<Noop, str>::from_static("static");
+    let cloned = literal.clone();
+    let borrowed = unsafe { Flake::from_borrowed("static" as *const str) };
+    assert_eq!(live_allocations(), 0); // Borrowed static data is never allocated
+    drop((literal, cloned, borrowed));
+
+    assert_eq!(live_allocations(), 0);
+
+    // A panic while initializing a slice tears the partial block down
+    let panicked = std::panic::catch_unwind(|| {
+        Rime::<AtomicUsize, [u32]>::from_iter_map(0..8, |i| if i < 4 { i } else { panic!("element {i}") })
+    });
+    assert!(panicked.is_err());
+    assert_eq!(live_allocations(), 0);
+
+    // A block taken back from a forgotten `Rime` is not counted again
+    let rime = Rime::<u8, [u8]>::new(b"round trip");
+    let (base, len) = (rime.counter_ptr(), rime.len());
+    std::mem::forget(rime);
+    let rime = unsafe { Rime::<u8, [u8]>::from_counter_ptr(base, len) };
+    assert_eq!(live_allocations(), 1);
+    drop(rime);
+
+    // A block from outside the crate is counted on adoption and untracked when freed
+    let (layout, offset) = Layout::new::<u8>().extend(Layout::new::<u32>()).unwrap();
+    let layout = layout.pad_to_align();
+    let rime = unsafe {
+        let block = alloc(layout);
+        block.write(1);
+        block.add(offset).cast::<u32>().write(0xC0FFEE);
+        Rime::<u8, u32>::from_raw_with_layout(block, block.add(offset).cast(), layout)
+    };
+    assert_eq!((*rime, live_allocations()), (0xC0FFEE, 1));
+    drop(rime);
+    assert_eq!(live_allocations(), 0);
+}
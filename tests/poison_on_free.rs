@@ -0,0 +1,55 @@
+#![cfg(any(debug_assertions, feature = "poison-on-free"))]
+
+use std::{alloc::{GlobalAlloc, Layout, System}, sync::atomic::{AtomicBool, AtomicUsize, Ordering}};
+use kroos::{Flake, Rime};
+
+/// Records whether the watched block was filled with the poison pattern when it was freed.
+struct Inspecting;
+
+static WATCHED: AtomicUsize = AtomicUsize::new(0);
+static FREED: AtomicBool = AtomicBool::new(false);
+static POISONED: AtomicBool = AtomicBool::new(false);
+
+unsafe impl GlobalAlloc for Inspecting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if ptr as usize == WATCHED.load(Ordering::Relaxed) {
+            let block = unsafe { std::slice::from_raw_parts(ptr, layout.size()) };
+            POISONED.store(block.iter().all(|&byte| byte == 0xDE), Ordering::Relaxed);
+            FREED.store(true, Ordering::Relaxed);
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Inspecting = Inspecting;
+
+fn watch(block: *const u8) {
+    FREED.store(false, Ordering::Relaxed);
+    WATCHED.store(block as usize, Ordering::Relaxed);
+}
+
+#[test]
+fn freed_blocks_are_poisoned() {
+    let rime = Rime::<AtomicUsize, [u8]>::new(b"stale data");
+    let clone = rime.clone();
+    watch(rime.counter_ptr().cast());
+
+    drop(rime);
+    assert!(!FREED.load(Ordering::Relaxed));
+    drop(clone);
+    assert!(FREED.load(Ordering::Relaxed));
+    assert!(POISONED.load(Ordering::Relaxed)); // Counter and payload alike
+
+    let flake = Flake::new("stale text");
+    watch(flake.as_ptr().cast());
+    drop(flake);
+    assert!(FREED.load(Ordering::Relaxed));
+    assert!(POISONED.load(Ordering::Relaxed));
+
+    WATCHED.store(0, Ordering::Relaxed);
+}
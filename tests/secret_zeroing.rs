@@ -0,0 +1,83 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, sync::{Mutex, atomic::{AtomicBool, AtomicUsize, Ordering}}};
+use kroos::{Rime, Secret};
+
+/// Records whether the watched payload was scrubbed at the moment its block was freed.
+struct Inspecting;
+
+/// Whether freed blocks are poisoned, which overwrites the zeroes with `0xDE` before the free.
+const POISON: bool = cfg!(any(debug_assertions, feature = "poison-on-free"));
+
+static WATCHED: AtomicUsize = AtomicUsize::new(0);
+static WATCHED_LEN: AtomicUsize = AtomicUsize::new(0);
+static FREED: AtomicBool = AtomicBool::new(false);
+static SCRUBBED: AtomicBool = AtomicBool::new(false);
+
+/// Serializes the tests, which share the watched block.
+static LOCK: Mutex<()> = Mutex::new(());
+
+unsafe impl GlobalAlloc for Inspecting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let (start, watched) = (ptr as usize, WATCHED.load(Ordering::Relaxed));
+        if watched != 0 && (start..start + layout.size()).contains(&watched) {
+            let payload = unsafe { std::slice::from_raw_parts(watched as *const u8, WATCHED_LEN.load(Ordering::Relaxed)) };
+            let fill = if POISON { 0xDE } else { 0 };
+            SCRUBBED.store(payload.iter().all(|&byte| byte == fill), Ordering::Relaxed);
+            FREED.store(true, Ordering::Relaxed);
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Inspecting = Inspecting;
+
+fn watch(bytes: &[u8]) {
+    FREED.store(false, Ordering::Relaxed);
+    WATCHED_LEN.store(bytes.len(), Ordering::Relaxed);
+    WATCHED.store(bytes.as_ptr() as usize, Ordering::Relaxed);
+}
+
+#[test]
+fn secret_is_zeroed_before_free() {
+    let _guard = LOCK.lock().unwrap();
+    let secret = Secret::<AtomicUsize>::new(Rime::new(b"correct horse battery staple"));
+    let clone = secret.clone();
+    watch(&secret);
+
+    drop(secret);
+    assert!(!FREED.load(Ordering::Relaxed)); // `clone` keeps the block alive
+    drop(clone);
+    assert!(FREED.load(Ordering::Relaxed));
+    assert!(SCRUBBED.load(Ordering::Relaxed));
+
+    // Below the threshold the payload is freed as-is, unless poisoning fills it
+    let small = Secret::<AtomicUsize, 64>::new(Rime::new(b"pin 1234"));
+    watch(&small);
+    drop(small);
+    assert!(FREED.load(Ordering::Relaxed));
+    assert_eq!(SCRUBBED.load(Ordering::Relaxed), POISON);
+
+    WATCHED.store(0, Ordering::Relaxed);
+}
+
+#[test]
+fn zeroed_secret_is_zeroed_before_free() {
+    let _guard = LOCK.lock().unwrap();
+    let mut secret = Rime::<AtomicUsize, [u8]>::new_zeroed_secret(32);
+    assert!(secret.iter().all(|&byte| byte == 0));
+    secret.get_mut().unwrap().fill(0xA5);
+
+    let clone = secret.clone();
+    watch(&secret);
+    drop(secret);
+    assert!(!FREED.load(Ordering::Relaxed));
+    drop(clone);
+    assert!(FREED.load(Ordering::Relaxed));
+    assert!(SCRUBBED.load(Ordering::Relaxed));
+
+    WATCHED.store(0, Ordering::Relaxed);
+}
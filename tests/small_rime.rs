@@ -0,0 +1,37 @@
+use std::{alloc::{GlobalAlloc, Layout, System}, sync::atomic::{AtomicUsize, Ordering}};
+use kroos::SmallRime;
+
+/// Counts every allocation made by the test binary.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn short_strings_stay_inline() {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let short = SmallRime::<AtomicUsize, str, 24>::new("fits in the handle");
+    let clone = short.clone();
+    assert_eq!(ALLOCATIONS.load(Ordering::Relaxed), before);
+    assert!(clone.is_inline());
+    assert_eq!(&*clone, "fits in the handle");
+
+    let long = SmallRime::<AtomicUsize, str, 24>::new("too long to fit in the handle");
+    let shared = long.clone();
+    assert_eq!(ALLOCATIONS.load(Ordering::Relaxed), before + 1); // One block, shared by both
+    assert!(!shared.is_inline());
+    assert_eq!(shared.as_rime().unwrap().strong_count(), 2);
+}